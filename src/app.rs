@@ -1,16 +1,24 @@
+use std::collections::HashMap;
+
 use cross_storage::{storage_get, storage_set};
 
 use dioxus::prelude::*;
 
-use lucide_dioxus::{ScanEye, Send};
+use lucide_dioxus::{ScanEye, Send, Settings2};
+
+use wingedcap::get_current_unix_time;
 
 use crate::{
+    components::{HeartbeatScheduler, ReceiverPoller},
+    storage::{get_settings, is_vault_enabled, is_vault_locked, lock_vault, migrate_records},
+    types::{Heartbeat, ReceiverPoll, Theme, VaultSession},
     ui::{
         popover::POPOVER_TARGET_ID,
         tabs::{Tabs, TabsContent, TabsList, TabsTrigger},
         toast::Toaster,
     },
-    views::{ReceiverView, SenderView},
+    utils::wait,
+    views::{ReceiverView, SenderView, SettingsView, UnlockView},
 };
 
 const FAVICON: Asset = asset!("/icons/icon.svg");
@@ -26,6 +34,70 @@ pub fn App() -> Element {
         let _ = storage_set("preferred_role", &tab);
     };
 
+    let settings = use_context_provider(|| Signal::new(get_settings().unwrap_or_default()));
+
+    // latest poll of each receiver by receiver id, filled by the ReceiverPoller
+    use_context_provider(|| Signal::new(HashMap::<String, ReceiverPoll>::new()));
+
+    // every sender with its last ping by sender id, filled by the HeartbeatScheduler
+    use_context_provider(|| Signal::new(HashMap::<String, Heartbeat>::new()));
+
+    use_effect(move || {
+        let theme = match settings().theme {
+            Theme::System => {
+                "window.matchMedia('(prefers-color-scheme: dark)').matches ? 'dark' : 'light'"
+                    .to_string()
+            }
+            theme => format!("'{theme}'"),
+        };
+
+        document::eval(&format!(
+            "document.documentElement.dataset.theme = {theme};"
+        ));
+    });
+
+    let VaultSession {
+        mut is_locked,
+        mut last_activity,
+    } = use_context_provider(|| VaultSession {
+        is_locked: Signal::new(is_vault_locked()),
+        last_activity: Signal::new(get_current_unix_time()),
+    });
+
+    // encrypted records can only be migrated once the vault is unlocked
+    use_effect(move || {
+        if is_locked() {
+            return;
+        }
+
+        match migrate_records() {
+            Ok(failures) => {
+                for failure in failures {
+                    tracing::error!("error migrating {}: {}", failure.storage_id, failure.error);
+                }
+            }
+
+            Err(e) => {
+                tracing::error!("error migrating records: {:?}", e);
+            }
+        }
+    });
+
+    use_future(move || async move {
+        loop {
+            wait(1000).await;
+
+            let auto_lock = settings.peek().vault_auto_lock as i64;
+
+            let is_idle = get_current_unix_time() - *last_activity.peek() >= auto_lock;
+
+            if auto_lock > 0 && is_idle && !*is_locked.peek() && is_vault_enabled() {
+                lock_vault();
+                is_locked.set(true);
+            }
+        }
+    });
+
     rsx! {
         document::Link { rel: "icon", href: FAVICON }
 
@@ -37,27 +109,43 @@ pub fn App() -> Element {
 
         Toaster {
 
-            main { class: "relative mx-auto max-w-4xl h-screen w-full min-w-0 px-4 sm:px-6 md:px-8",
+            main {
+                class: "relative mx-auto max-w-4xl h-screen w-full min-w-0 px-4 sm:px-6 md:px-8",
+                onpointerdown: move |_| last_activity.set(get_current_unix_time()),
+                onkeydown: move |_| last_activity.set(get_current_unix_time()),
                 div { class: "relative flex h-full items-center justify-center py-8",
                     div { class: "w-full self-start pt-[12vh] sm:pt-[15vh] md:pt-[18vh]",
 
-                        Tabs {
-                            default_tab: preferred_role,
-                            class: "w-full",
-                            on_change: on_tab_change,
-                            TabsList { class: "mb-4",
-                                TabsTrigger { id: "send",
-                                    "Sender"
-                                    Send { class: "ml-1.5 size-3.5" }
-                                }
-                                TabsTrigger { id: "receive",
-                                    "Receiver"
-                                    ScanEye { class: "ml-1.5 size-3.5" }
+                        HeartbeatScheduler {}
+
+                        if is_locked() {
+                            UnlockView {}
+                        } else {
+                            ReceiverPoller {}
+
+                            Tabs {
+                                default_tab: preferred_role,
+                                class: "w-full",
+                                on_change: on_tab_change,
+                                TabsList { class: "mb-4",
+                                    TabsTrigger { id: "send",
+                                        "Sender"
+                                        Send { class: "ml-1.5 size-3.5" }
+                                    }
+                                    TabsTrigger { id: "receive",
+                                        "Receiver"
+                                        ScanEye { class: "ml-1.5 size-3.5" }
+                                    }
+                                    TabsTrigger { id: "settings",
+                                        "Settings"
+                                        Settings2 { class: "ml-1.5 size-3.5" }
+                                    }
                                 }
-                            }
 
-                            TabsContent { id: "send", class: "animate-fade-in", SenderView {} }
-                            TabsContent { id: "receive", class: "animate-fade-in", ReceiverView {} }
+                                TabsContent { id: "send", class: "animate-fade-in", SenderView {} }
+                                TabsContent { id: "receive", class: "animate-fade-in", ReceiverView {} }
+                                TabsContent { id: "settings", class: "animate-fade-in", SettingsView {} }
+                            }
                         }
                     }
                 }
@@ -0,0 +1,121 @@
+use serde_json::json;
+
+use wingedcap_client::{
+    storage::{create_backup, diff_backup, read_backup, restore_backup},
+    types::{BackupFile, BackupRecordStatus},
+};
+
+use crate::constants::BACKUP_PASSPHRASE_ENV;
+use crate::types::{BackupCommand, BackupExportArgs, BackupRestoreArgs, CommandOutput};
+use crate::utils::{read_input, read_passphrase};
+
+pub fn backup_command_handler(backup_command: BackupCommand) -> Result<CommandOutput, String> {
+    match backup_command {
+        BackupCommand::Export(BackupExportArgs { path, encrypt }) => {
+            let passphrase = if encrypt {
+                Some(read_passphrase(BACKUP_PASSPHRASE_ENV)?)
+            } else {
+                None
+            };
+
+            let backup = create_backup(passphrase.as_deref())?;
+
+            let backup_json = serde_json::to_string_pretty(&backup).map_err(|e| e.to_string())?;
+
+            match path {
+                Some(path) => {
+                    std::fs::write(&path, backup_json).map_err(|e| e.to_string())?;
+
+                    Ok(CommandOutput {
+                        json: json!({ "path": path, "encrypted": encrypt }),
+                        text: format!("backup written to {}", path),
+                    })
+                }
+
+                None => Ok(CommandOutput {
+                    json: serde_json::to_value(&backup).map_err(|e| e.to_string())?,
+                    text: backup_json,
+                }),
+            }
+        }
+
+        BackupCommand::Restore(BackupRestoreArgs {
+            path,
+            dry_run,
+            overwrite,
+            settings,
+        }) => {
+            let backup_json = read_input(Some(&path))?;
+
+            let is_encrypted = serde_json::from_str::<BackupFile>(&backup_json)
+                .map(|backup| backup.salt.is_some())
+                .unwrap_or(false);
+
+            let passphrase = if is_encrypted {
+                Some(read_passphrase(BACKUP_PASSPHRASE_ENV)?)
+            } else {
+                None
+            };
+
+            let content = read_backup(&backup_json, passphrase.as_deref())?;
+
+            let diff = diff_backup(&content)?;
+
+            let status_name = |status: &BackupRecordStatus| match status {
+                BackupRecordStatus::New => "new",
+                BackupRecordStatus::Identical => "identical",
+                BackupRecordStatus::Conflicting => "conflicting",
+            };
+
+            let records: Vec<(&str, String, &str)> = diff
+                .senders
+                .iter()
+                .map(|(backup_sender, status)| {
+                    (
+                        "sender",
+                        backup_sender.sender.label.clone(),
+                        status_name(status),
+                    )
+                })
+                .chain(diff.receivers.iter().map(|(backup_receiver, status)| {
+                    (
+                        "receiver",
+                        backup_receiver.receiver.label.clone(),
+                        status_name(status),
+                    )
+                }))
+                .collect();
+
+            let restored = if dry_run {
+                0
+            } else {
+                restore_backup(&content, overwrite, settings)?
+            };
+
+            let lines: Vec<String> = records
+                .iter()
+                .map(|(role, label, status)| format!("{:<12}{:<10}{}", status, role, label))
+                .collect();
+
+            Ok(CommandOutput {
+                json: json!({
+                    "records": records
+                        .iter()
+                        .map(|(role, label, status)| json!({ "role": role, "label": label, "status": status }))
+                        .collect::<Vec<_>>(),
+                    "restored": restored,
+                    "dry_run": dry_run,
+                }),
+                text: format!(
+                    "{}\n\n{}",
+                    lines.join("\n"),
+                    if dry_run {
+                        "dry run, nothing restored".to_string()
+                    } else {
+                        format!("restored {} records", restored)
+                    }
+                ),
+            })
+        }
+    }
+}
@@ -0,0 +1,173 @@
+use std::path::Path;
+
+use serde_json::json;
+
+use wingedcap::{
+    client::{generate_standard_vault_sets, KeyIndexArray},
+    get_current_unix_time, GetServerInput,
+};
+
+use wingedcap_client::{
+    constants::MAX_FILE_ATTACHMENT_SIZE,
+    manager::{get_default_hub, get_hubs, get_server},
+    storage::{get_sender_id, get_settings, store_sender, store_sender_bundle, store_sender_meta},
+    types::{FileAttachment, SenderBundle, SenderMeta, ShareStatus},
+    utils::{
+        create_secret, encode_file_envelope, encrypt_message_with_passphrase, guess_mime_type,
+        parse_time, validate_vault_sets,
+    },
+};
+
+use crate::constants::RECIPIENT_PASSPHRASE_ENV;
+use crate::types::{CommandOutput, CreateArgs};
+use crate::utils::{parse_vault_set, read_input, read_passphrase};
+
+pub async fn create_command(
+    CreateArgs {
+        label,
+        message,
+        file,
+        protect,
+        timelock,
+        keys,
+        required,
+        sets,
+        hub,
+        retries,
+        backoff,
+        allow_partial,
+    }: CreateArgs,
+) -> Result<CommandOutput, String> {
+    let timelock = parse_time(&timelock)?;
+
+    if timelock.magnitude == 0 {
+        return Err("timelock must be greater than zero".to_string());
+    }
+
+    let sets: Vec<KeyIndexArray> = if sets.is_empty() {
+        if required == 0 || required > keys {
+            return Err(format!("required keys must be between 1 and {}", keys));
+        }
+
+        generate_standard_vault_sets(keys, required)
+    } else {
+        sets.iter()
+            .map(|set| parse_vault_set(set))
+            .collect::<Result<_, _>>()?
+    };
+
+    validate_vault_sets(&sets, keys)?;
+
+    let message = match (message, file) {
+        (Some(message), _) => message,
+
+        (None, Some(path)) => {
+            let bytes = std::fs::read(&path).map_err(|e| e.to_string())?;
+
+            if bytes.len() as u64 > MAX_FILE_ATTACHMENT_SIZE {
+                return Err(format!(
+                    "file is too large, attachments are limited to {} bytes",
+                    MAX_FILE_ATTACHMENT_SIZE
+                ));
+            }
+
+            let name = Path::new(&path)
+                .file_name()
+                .map(|name| name.to_string_lossy().to_string())
+                .ok_or("file has no name")?;
+
+            encode_file_envelope(&FileAttachment {
+                mime: guess_mime_type(&name),
+                name,
+                bytes,
+            })?
+        }
+
+        (None, None) => read_input(None)?,
+    };
+
+    if message.is_empty() {
+        return Err("message is empty".to_string());
+    }
+
+    let message = if protect {
+        encrypt_message_with_passphrase(&message, &read_passphrase(RECIPIENT_PASSPHRASE_ENV)?)?
+    } else {
+        message
+    };
+
+    let hub = match hub {
+        Some(name) => get_hubs()
+            .into_iter()
+            .find(|hub| hub.name == name)
+            .ok_or(format!("no hub named \"{}\"", name))?,
+
+        None => get_default_hub()?,
+    };
+
+    let mut servers = vec![];
+
+    for _ in 0..keys {
+        servers.push(get_server(&hub, &GetServerInput {}).await?);
+    }
+
+    let mut options = get_settings()?.key_creation;
+
+    options.retries = retries.unwrap_or(options.retries);
+    options.backoff = backoff.unwrap_or(options.backoff);
+    options.allow_partial = allow_partial || options.allow_partial;
+
+    let sender_meta = SenderMeta {
+        timelock: Some(timelock.clone()),
+        created_at: Some(get_current_unix_time()),
+        required_keys: sets.iter().map(|set| set.len() as u64).min(),
+        ..Default::default()
+    };
+
+    let (sender, receiver, outcomes) =
+        create_secret(label, message, timelock, servers, sets, &options).await?;
+
+    let failures: Vec<String> = outcomes
+        .iter()
+        .filter_map(|outcome| {
+            outcome.error.as_ref().map(|error| {
+                format!(
+                    "{} failed after {} attempt(s): {}",
+                    outcome.server.host, outcome.attempts, error
+                )
+            })
+        })
+        .collect();
+
+    let id = get_sender_id(&sender)?;
+
+    store_sender_meta(&sender, sender_meta)?;
+
+    // printed below, so it counts as copied until the recipient confirms
+    store_sender_bundle(
+        &sender,
+        SenderBundle {
+            receiver: receiver.clone(),
+            share_status: ShareStatus::Copied,
+        },
+    )?;
+
+    store_sender(sender)?;
+
+    let receiver_json = serde_json::to_string_pretty(&receiver).map_err(|e| e.to_string())?;
+
+    Ok(CommandOutput {
+        json: json!({ "id": id, "receiver": receiver, "outcomes": outcomes }),
+        text: format!(
+            "created sender {} with {} of {} keys{}\n\nshare the receiver data below with the recipient:\n{}",
+            id,
+            outcomes.len() - failures.len(),
+            outcomes.len(),
+            failures
+                .iter()
+                .map(|failure| format!("\n{}", failure))
+                .collect::<String>(),
+            receiver_json
+        ),
+    })
+}
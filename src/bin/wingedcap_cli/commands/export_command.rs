@@ -0,0 +1,40 @@
+use serde_json::json;
+
+use wingedcap::client::{ReceiverStored, SenderStored};
+
+use wingedcap_client::storage::{
+    constants::{RECEIVER_STORAGE_NAME_PREFIX, SENDER_STORAGE_NAME_PREFIX},
+    get_receivers, get_senders,
+};
+
+use crate::types::{CommandOutput, RecordArgs};
+use crate::utils::find_record;
+
+pub fn export_command(RecordArgs { id }: RecordArgs) -> Result<CommandOutput, String> {
+    let sender = find_record(
+        get_senders()?.records,
+        SENDER_STORAGE_NAME_PREFIX,
+        &id,
+        |sender: &SenderStored| sender.label.clone(),
+    )?;
+
+    let record = match sender {
+        Some((_, sender)) => json!(sender),
+
+        None => {
+            let (_, receiver) = find_record(
+                get_receivers()?.records,
+                RECEIVER_STORAGE_NAME_PREFIX,
+                &id,
+                |receiver: &ReceiverStored| receiver.label.clone(),
+            )?
+            .ok_or(format!("no sender or receiver matches \"{}\"", id))?;
+
+            json!(receiver)
+        }
+    };
+
+    let text = serde_json::to_string_pretty(&record).map_err(|e| e.to_string())?;
+
+    Ok(CommandOutput { json: record, text })
+}
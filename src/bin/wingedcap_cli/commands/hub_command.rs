@@ -0,0 +1,89 @@
+use serde_json::json;
+
+use wingedcap_client::{
+    manager::{get_default_hub, get_hubs},
+    storage::{get_settings, store_settings},
+    types::{Hub, HubSettings},
+};
+
+use crate::types::{CommandOutput, HubAddArgs, HubCommand, HubNameArgs};
+
+pub fn hub_command_handler(hub_command: HubCommand) -> Result<CommandOutput, String> {
+    let mut settings = get_settings()?;
+
+    let hub_settings = &mut settings.hubs;
+
+    match hub_command {
+        HubCommand::List => {
+            let default_hub_name = get_default_hub().ok().map(|hub| hub.name);
+
+            let hubs = get_hubs();
+
+            let lines: Vec<String> = hubs
+                .iter()
+                .map(|hub| {
+                    let marker = if Some(hub.name.clone()) == default_hub_name {
+                        "*"
+                    } else {
+                        " "
+                    };
+
+                    format!("{} {}  {}", marker, hub.name, hub.host)
+                })
+                .collect();
+
+            return Ok(CommandOutput {
+                json: json!({ "hubs": hubs, "default_hub": default_hub_name }),
+                text: lines.join("\n"),
+            });
+        }
+
+        HubCommand::Add(HubAddArgs {
+            name,
+            host,
+            pk,
+            default,
+        }) => {
+            hub_settings.hubs.retain(|hub| hub.name != name);
+
+            hub_settings.hubs.push(Hub {
+                name: name.clone(),
+                host,
+                pk,
+            });
+
+            if default {
+                hub_settings.default_hub = Some(name);
+            }
+        }
+
+        HubCommand::Remove(HubNameArgs { name }) => {
+            if !hub_settings.hubs.iter().any(|hub| hub.name == name) {
+                return Err(format!("no stored hub named \"{}\"", name));
+            }
+
+            hub_settings.hubs.retain(|hub| hub.name != name);
+
+            if hub_settings.default_hub == Some(name) {
+                hub_settings.default_hub = None;
+            }
+        }
+
+        HubCommand::Default(HubNameArgs { name }) => {
+            if !get_hubs().iter().any(|hub| hub.name == name) {
+                return Err(format!("no hub named \"{}\"", name));
+            }
+
+            hub_settings.default_hub = Some(name);
+        }
+    }
+
+    let HubSettings { hubs, default_hub } = hub_settings.clone();
+
+    store_settings(settings)?;
+
+    Ok(CommandOutput {
+        text: "hubs updated".to_string(),
+        json: json!({ "hubs": hubs, "default_hub": default_hub }),
+    })
+}
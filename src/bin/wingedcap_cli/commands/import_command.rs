@@ -0,0 +1,103 @@
+use serde_json::json;
+
+use wingedcap::client::{Receiver, ReceiverStored, SenderStored};
+
+use wingedcap_client::storage::{
+    find_receiver_conflict, get_receiver_id, get_sender_id, get_storage_name, store_receiver,
+    store_receiver_with_resolution, store_sender,
+};
+use wingedcap_client::types::ConflictResolution;
+
+use crate::types::{CommandOutput, ImportArgs, OnConflict};
+use crate::utils::read_input;
+
+pub fn import_command(
+    ImportArgs {
+        path,
+        label,
+        on_conflict,
+        rename_to,
+    }: ImportArgs,
+) -> Result<CommandOutput, String> {
+    let content = read_input(path.as_deref())?;
+
+    if let Ok(sender) = serde_json::from_str::<SenderStored>(&content) {
+        let id = get_sender_id(&sender)?;
+        let label = label.unwrap_or(sender.label.clone());
+
+        store_sender(SenderStored {
+            label: label.clone(),
+            ..sender
+        })?;
+
+        return Ok(CommandOutput {
+            text: format!("imported sender {} ({})", label, id),
+            json: json!({ "role": "sender", "id": id, "label": label }),
+        });
+    }
+
+    let receiver = match serde_json::from_str::<ReceiverStored>(&content) {
+        Ok(receiver) => ReceiverStored {
+            label: label.unwrap_or(receiver.label.clone()),
+            ..receiver
+        },
+
+        Err(_) => {
+            let Receiver { keys, sets } = serde_json::from_str::<Receiver>(&content)
+                .map_err(|_| "input is neither a sender nor a receiver".to_string())?;
+
+            let label = label.ok_or("--label is required to import receiver data")?;
+
+            ReceiverStored { label, keys, sets }
+        }
+    };
+
+    let conflict = find_receiver_conflict(&receiver)?;
+
+    let receiver = match (conflict, on_conflict) {
+        (None, _) => {
+            store_receiver(receiver.clone())?;
+
+            receiver
+        }
+
+        (Some((storage_id, existing)), None) => {
+            return Err(format!(
+                "a stored receiver conflicts with this one: {} ({}), use --on-conflict merge|rename|replace",
+                existing.label,
+                get_storage_name(&storage_id)
+            ));
+        }
+
+        (Some(_), Some(OnConflict::Merge)) => {
+            store_receiver_with_resolution(receiver.clone(), ConflictResolution::Merge)?;
+
+            receiver
+        }
+
+        (Some(_), Some(OnConflict::Rename)) => {
+            let label = rename_to.ok_or("--rename-to is required with --on-conflict rename")?;
+
+            store_receiver_with_resolution(
+                receiver.clone(),
+                ConflictResolution::Rename(label.clone()),
+            )?;
+
+            ReceiverStored { label, ..receiver }
+        }
+
+        (Some(_), Some(OnConflict::Replace)) => {
+            store_receiver_with_resolution(receiver.clone(), ConflictResolution::Replace)?;
+
+            receiver
+        }
+    };
+
+    let id = get_receiver_id(&receiver)?;
+    let label = receiver.label;
+
+    Ok(CommandOutput {
+        text: format!("imported receiver {} ({})", label, id),
+        json: json!({ "role": "receiver", "id": id, "label": label }),
+    })
+}
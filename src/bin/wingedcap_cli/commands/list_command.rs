@@ -0,0 +1,96 @@
+use serde_json::{json, Value};
+
+use wingedcap_client::storage::{
+    constants::{RECEIVER_STORAGE_NAME_PREFIX, SENDER_STORAGE_NAME_PREFIX},
+    get_receivers, get_sender_bundle, get_sender_meta, get_senders,
+};
+
+use wingedcap_client::types::{LoadedRecords, RecordFailure, SystemClock};
+use wingedcap_client::utils::format_time_ago;
+
+use crate::types::CommandOutput;
+use crate::utils::get_record_id;
+
+pub fn list_command() -> Result<CommandOutput, String> {
+    let mut records: Vec<Value> = vec![];
+    let mut lines: Vec<String> = vec![];
+
+    let LoadedRecords {
+        records: senders,
+        failures: sender_failures,
+    } = get_senders()?;
+
+    let LoadedRecords {
+        records: receivers,
+        failures: receiver_failures,
+    } = get_receivers()?;
+
+    for (storage_id, sender) in senders {
+        let id = get_record_id(&storage_id, SENDER_STORAGE_NAME_PREFIX);
+
+        let share_status = get_sender_bundle(&sender)
+            .ok()
+            .flatten()
+            .map(|bundle| bundle.share_status);
+
+        let last_ping = get_sender_meta(&sender)
+            .ok()
+            .and_then(|meta| meta.last_ping());
+
+        lines.push(format!(
+            "sender    {}  {}{}",
+            id,
+            sender.label,
+            last_ping
+                .map(|last_ping| format!(
+                    "  (last ping {})",
+                    format_time_ago(last_ping, &SystemClock)
+                ))
+                .unwrap_or_default()
+        ));
+
+        records.push(json!({
+            "role": "sender",
+            "id": id,
+            "label": sender.label,
+            "keys": sender.keys.len(),
+            "share_status": share_status,
+            "last_ping": last_ping,
+        }));
+    }
+
+    for (storage_id, receiver) in receivers {
+        let id = get_record_id(&storage_id, RECEIVER_STORAGE_NAME_PREFIX);
+
+        lines.push(format!("receiver  {}  {}", id, receiver.label));
+
+        records.push(json!({
+            "role": "receiver",
+            "id": id,
+            "label": receiver.label,
+            "keys": receiver.keys.len(),
+        }));
+    }
+
+    let failures = sender_failures
+        .into_iter()
+        .map(|failure| ("sender", SENDER_STORAGE_NAME_PREFIX, failure))
+        .chain(
+            receiver_failures
+                .into_iter()
+                .map(|failure| ("receiver", RECEIVER_STORAGE_NAME_PREFIX, failure)),
+        );
+
+    for (role, prefix, RecordFailure { storage_id, error }) in failures {
+        let id = get_record_id(&storage_id, prefix);
+
+        lines.push(format!("{:<8}  {}  unreadable: {}", role, id, error));
+
+        records.push(json!({ "role": role, "id": id, "error": error }));
+    }
+
+    Ok(CommandOutput {
+        json: Value::Array(records),
+        text: lines.join("\n"),
+    })
+}
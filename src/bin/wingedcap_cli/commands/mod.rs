@@ -0,0 +1,32 @@
+mod create_command;
+pub use create_command::*;
+
+mod ping_command;
+pub use ping_command::*;
+
+mod ping_all_command;
+pub use ping_all_command::*;
+
+mod list_command;
+pub use list_command::*;
+
+mod receive_command;
+pub use receive_command::*;
+
+mod import_command;
+pub use import_command::*;
+
+mod export_command;
+pub use export_command::*;
+
+mod share_command;
+pub use share_command::*;
+
+mod hub_command;
+pub use hub_command::*;
+
+mod vault_command;
+pub use vault_command::*;
+
+mod backup_command;
+pub use backup_command::*;
@@ -0,0 +1,67 @@
+use serde_json::{json, Value};
+
+use wingedcap::{
+    client::{Key, Sender, SenderStored},
+    get_current_unix_time,
+};
+
+use wingedcap_client::{
+    storage::{constants::SENDER_STORAGE_NAME_PREFIX, get_senders, record_sender_ping},
+    utils::ping_secret,
+};
+
+use crate::types::CommandOutput;
+use crate::utils::{format_keys_health, get_record_id, sender_state_to_json};
+
+pub async fn ping_all_command() -> Result<CommandOutput, String> {
+    let senders = get_senders()?.records;
+
+    let mut results: Vec<Value> = vec![];
+    let mut lines: Vec<String> = vec![];
+
+    for (storage_id, sender) in senders {
+        let SenderStored { label, keys, sets } = sender.clone();
+
+        let id = get_record_id(&storage_id, SENDER_STORAGE_NAME_PREFIX);
+
+        let keys: Vec<Key> = keys
+            .iter()
+            .map(|key| Key {
+                host: key.host.clone(),
+                pk: key.pk.clone(),
+                id: key.id.clone(),
+            })
+            .collect();
+
+        match ping_secret(Sender { keys, sets }).await {
+            Ok((state, keys_health)) => {
+                if let Err(e) = record_sender_ping(&sender, &keys_health, get_current_unix_time()) {
+                    lines.push(format!("{} ({}): error recording ping: {}", label, id, e));
+                }
+
+                let state_json = sender_state_to_json(&state, &keys_health);
+
+                lines.push(format!(
+                    "{} ({}): {}{}",
+                    label,
+                    id,
+                    state_json["state"].as_str().unwrap_or(""),
+                    format_keys_health(&keys_health)
+                ));
+
+                results.push(json!({ "id": id, "label": label, "state": state_json }));
+            }
+
+            Err(e) => {
+                lines.push(format!("{} ({}): error: {}", label, id, e));
+
+                results.push(json!({ "id": id, "label": label, "error": e }));
+            }
+        }
+    }
+
+    Ok(CommandOutput {
+        json: Value::Array(results),
+        text: lines.join("\n"),
+    })
+}
@@ -0,0 +1,54 @@
+use serde_json::json;
+
+use wingedcap::{
+    client::{Key, Sender, SenderStored},
+    get_current_unix_time,
+};
+
+use wingedcap_client::{
+    storage::{constants::SENDER_STORAGE_NAME_PREFIX, get_senders, record_sender_ping},
+    utils::ping_secret,
+};
+
+use crate::types::{CommandOutput, RecordArgs};
+use crate::utils::{find_record, format_keys_health, get_record_id, sender_state_to_json};
+
+pub async fn ping_command(RecordArgs { id }: RecordArgs) -> Result<CommandOutput, String> {
+    let (storage_id, sender) = find_record(
+        get_senders()?.records,
+        SENDER_STORAGE_NAME_PREFIX,
+        &id,
+        |sender: &SenderStored| sender.label.clone(),
+    )?
+    .ok_or(format!("no sender matches \"{}\"", id))?;
+
+    let SenderStored { label, keys, sets } = sender.clone();
+
+    let keys: Vec<Key> = keys
+        .iter()
+        .map(|key| Key {
+            host: key.host.clone(),
+            pk: key.pk.clone(),
+            id: key.id.clone(),
+        })
+        .collect();
+
+    let (state, keys_health) = ping_secret(Sender { keys, sets }).await?;
+
+    record_sender_ping(&sender, &keys_health, get_current_unix_time())?;
+
+    let state_json = sender_state_to_json(&state, &keys_health);
+
+    let id = get_record_id(&storage_id, SENDER_STORAGE_NAME_PREFIX);
+
+    Ok(CommandOutput {
+        text: format!(
+            "{} ({}): {}{}",
+            label,
+            id,
+            state_json["state"].as_str().unwrap_or(""),
+            format_keys_health(&keys_health)
+        ),
+        json: json!({ "id": id, "label": label, "state": state_json }),
+    })
+}
@@ -0,0 +1,165 @@
+use serde_json::json;
+
+use wingedcap::{
+    client::{Receiver, ReceiverState, ReceiverStored},
+    get_current_unix_time,
+};
+
+use wingedcap_client::{
+    storage::{constants::RECEIVER_STORAGE_NAME_PREFIX, get_receivers, record_receiver_unlock},
+    types::{FileAttachment, SecretPayload},
+    utils::{
+        decrypt_message_with_passphrase, detect_secret_payload, get_secret,
+        group_decrypted_messages,
+    },
+};
+
+use crate::constants::RECIPIENT_PASSPHRASE_ENV;
+use crate::types::{CommandOutput, ReceiveArgs};
+use crate::utils::{
+    find_record, format_keys_health, get_record_id, keys_health_to_json, read_passphrase,
+};
+
+pub async fn receive_command(
+    ReceiveArgs {
+        id,
+        output,
+        decrypt,
+    }: ReceiveArgs,
+) -> Result<CommandOutput, String> {
+    let (storage_id, receiver) = find_record(
+        get_receivers()?.records,
+        RECEIVER_STORAGE_NAME_PREFIX,
+        &id,
+        |receiver: &ReceiverStored| receiver.label.clone(),
+    )?
+    .ok_or(format!("no receiver matches \"{}\"", id))?;
+
+    let ReceiverStored { label, keys, sets } = receiver.clone();
+
+    let (state, keys_health) = get_secret(Receiver { keys, sets }).await?;
+
+    let keys_json = keys_health_to_json(&keys_health);
+
+    let keys_text = format_keys_health(&keys_health);
+
+    let id = get_record_id(&storage_id, RECEIVER_STORAGE_NAME_PREFIX);
+
+    let output = match state {
+        ReceiverState::Locked { .. } => CommandOutput {
+            text: format!("{} ({}): locked{}", label, id, keys_text),
+            json: json!({ "id": id, "label": label, "state": "locked", "keys": keys_json }),
+        },
+
+        ReceiverState::Unlocked { unlocked_sets, .. } => {
+            let (unlocked_at, _) = record_receiver_unlock(&receiver, get_current_unix_time())?;
+
+            let messages: Vec<String> = unlocked_sets
+                .iter()
+                .map(|set| set.decrypted_data.clone())
+                .collect();
+
+            let messages = if decrypt {
+                let passphrase = read_passphrase(RECIPIENT_PASSPHRASE_ENV)?;
+
+                messages
+                    .iter()
+                    .map(|message| match detect_secret_payload(message) {
+                        SecretPayload::PassphraseProtected(envelope) => {
+                            decrypt_message_with_passphrase(&envelope, &passphrase)
+                        }
+                        _ => Ok(message.clone()),
+                    })
+                    .collect::<Result<Vec<String>, String>>()?
+            } else {
+                messages
+            };
+
+            let groups = group_decrypted_messages(&messages);
+
+            // files are described rather than printed as base64
+            let describe = |message: &str| match detect_secret_payload(message) {
+                SecretPayload::File(FileAttachment { name, mime, bytes }) => {
+                    format!("file {} ({}, {} bytes)", name, mime, bytes.len())
+                }
+                SecretPayload::PassphraseProtected(_) => {
+                    "passphrase-protected message, pass --decrypt to open it".to_string()
+                }
+                _ => message.to_string(),
+            };
+
+            let first_message = messages.first().cloned().unwrap_or_default();
+
+            let file = match detect_secret_payload(&first_message) {
+                SecretPayload::File(attachment) => Some(attachment),
+                _ => None,
+            };
+
+            if let Some(output) = &output {
+                let content = match &file {
+                    Some(attachment) => attachment.bytes.clone(),
+                    None => first_message.clone().into_bytes(),
+                };
+
+                std::fs::write(output, content).map_err(|e| e.to_string())?;
+            }
+
+            let output_text = match (&output, &file) {
+                (Some(output), _) => format!("\n\nwritten to {}", output),
+                (None, Some(_)) => "\n\npass --output to write the file".to_string(),
+                (None, None) => String::new(),
+            };
+
+            let text = match groups.len() {
+                0 | 1 => describe(&first_message),
+
+                // shows every distinct message so that none goes unnoticed
+                _ => groups
+                    .iter()
+                    .map(|(message, set_indexes)| {
+                        let set_numbers: Vec<String> = set_indexes
+                            .iter()
+                            .map(|set_index| (set_index + 1).to_string())
+                            .collect();
+
+                        format!("sets {}:\n{}", set_numbers.join(", "), describe(message))
+                    })
+                    .collect::<Vec<String>>()
+                    .join("\n\n"),
+            };
+
+            let warning = if groups.len() > 1 {
+                format!(
+                    "\nwarning: the unlocked sets disagree, they hold {} different messages",
+                    groups.len()
+                )
+            } else {
+                String::new()
+            };
+
+            CommandOutput {
+                text: format!(
+                    "{} ({}): unlocked{}{}\n\n{}{}",
+                    label, id, keys_text, warning, text, output_text
+                ),
+                json: json!({
+                    "id": id,
+                    "label": label,
+                    "state": "unlocked",
+                    "unlocked_at": unlocked_at,
+                    "messages": messages,
+                    "consistent": groups.len() <= 1,
+                    "file": file.map(|attachment| json!({
+                        "name": attachment.name,
+                        "mime": attachment.mime,
+                        "size": attachment.bytes.len(),
+                    })),
+                    "output": output,
+                    "keys": keys_json,
+                }),
+            }
+        }
+    };
+
+    Ok(output)
+}
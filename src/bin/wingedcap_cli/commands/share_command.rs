@@ -0,0 +1,51 @@
+use serde_json::json;
+
+use wingedcap::client::SenderStored;
+
+use wingedcap_client::storage::{
+    constants::SENDER_STORAGE_NAME_PREFIX, get_sender_bundle, get_senders, set_sender_share_status,
+};
+
+use wingedcap_client::types::ShareStatus;
+
+use crate::types::{CommandOutput, ShareArgs};
+use crate::utils::{find_record, get_record_id};
+
+pub fn share_command(ShareArgs { id, confirm }: ShareArgs) -> Result<CommandOutput, String> {
+    let (storage_id, sender) = find_record(
+        get_senders()?.records,
+        SENDER_STORAGE_NAME_PREFIX,
+        &id,
+        |sender: &SenderStored| sender.label.clone(),
+    )?
+    .ok_or(format!("no sender matches \"{}\"", id))?;
+
+    let id = get_record_id(&storage_id, SENDER_STORAGE_NAME_PREFIX);
+
+    let bundle =
+        get_sender_bundle(&sender)?.ok_or(format!("no receiver data stored for sender {}", id))?;
+
+    if confirm {
+        set_sender_share_status(&sender, ShareStatus::Confirmed)?;
+
+        return Ok(CommandOutput {
+            json: json!({ "id": id, "share_status": ShareStatus::Confirmed }),
+            text: format!("marked the receiver data of sender {} as received", id),
+        });
+    }
+
+    // a confirmed share stays confirmed when printed again
+    let share_status = match bundle.share_status {
+        ShareStatus::NotShared => ShareStatus::Copied,
+        share_status => share_status,
+    };
+
+    set_sender_share_status(&sender, share_status)?;
+
+    let text = serde_json::to_string_pretty(&bundle.receiver).map_err(|e| e.to_string())?;
+
+    Ok(CommandOutput {
+        json: json!({ "id": id, "receiver": bundle.receiver, "share_status": share_status }),
+        text,
+    })
+}
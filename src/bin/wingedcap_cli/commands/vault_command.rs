@@ -0,0 +1,45 @@
+use serde_json::json;
+
+use wingedcap_client::storage::{disable_vault, enable_vault, is_vault_enabled, is_vault_locked};
+
+use crate::constants::PASSPHRASE_ENV;
+use crate::types::{CommandOutput, VaultCommand};
+use crate::utils::read_passphrase;
+
+pub fn vault_command_handler(vault_command: VaultCommand) -> Result<CommandOutput, String> {
+    match vault_command {
+        VaultCommand::Status => {
+            let status = match (is_vault_enabled(), is_vault_locked()) {
+                (false, _) => "disabled",
+                (true, true) => "locked",
+                (true, false) => "unlocked",
+            };
+
+            Ok(CommandOutput {
+                json: json!({ "vault": status }),
+                text: format!("vault {}", status),
+            })
+        }
+
+        VaultCommand::Enable => {
+            enable_vault(&read_passphrase(PASSPHRASE_ENV)?)?;
+
+            Ok(CommandOutput {
+                json: json!({ "vault": "unlocked" }),
+                text: format!(
+                    "vault enabled, set {} to unlock it in later commands",
+                    PASSPHRASE_ENV
+                ),
+            })
+        }
+
+        VaultCommand::Disable => {
+            disable_vault()?;
+
+            Ok(CommandOutput {
+                json: json!({ "vault": "disabled" }),
+                text: "vault disabled".to_string(),
+            })
+        }
+    }
+}
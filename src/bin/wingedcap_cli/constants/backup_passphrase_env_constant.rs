@@ -0,0 +1 @@
+pub const BACKUP_PASSPHRASE_ENV: &str = "WINGEDCAP_BACKUP_PASSPHRASE";
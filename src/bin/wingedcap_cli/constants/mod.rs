@@ -0,0 +1,8 @@
+mod passphrase_env_constant;
+pub use passphrase_env_constant::*;
+
+mod backup_passphrase_env_constant;
+pub use backup_passphrase_env_constant::*;
+
+mod recipient_passphrase_env_constant;
+pub use recipient_passphrase_env_constant::*;
@@ -0,0 +1 @@
+pub const PASSPHRASE_ENV: &str = "WINGEDCAP_PASSPHRASE";
@@ -0,0 +1 @@
+pub const RECIPIENT_PASSPHRASE_ENV: &str = "WINGEDCAP_RECIPIENT_PASSPHRASE";
@@ -0,0 +1,68 @@
+use clap::Parser;
+
+use wingedcap_client::storage::migrate_records;
+
+mod commands;
+
+mod constants;
+
+mod types;
+
+mod utils;
+
+use commands::*;
+
+use types::{Cli, Command, CommandOutput};
+
+use utils::unlock_vault_from_env;
+
+#[tokio::main]
+async fn main() {
+    let Cli { json, command } = Cli::parse();
+
+    if let Err(e) = unlock_vault_from_env() {
+        eprintln!("error: {}", e);
+        std::process::exit(1);
+    }
+
+    // records that can't be migrated are reported by the commands reading them
+    let _ = migrate_records();
+
+    let result = match command {
+        Command::Create(args) => create_command(args).await,
+        Command::Ping(args) => ping_command(args).await,
+        Command::PingAll => ping_all_command().await,
+        Command::List => list_command(),
+        Command::Receive(args) => receive_command(args).await,
+        Command::Import(args) => import_command(args),
+        Command::Export(args) => export_command(args),
+        Command::Share(args) => share_command(args),
+        Command::Hub(hub_command) => hub_command_handler(hub_command),
+        Command::Vault(vault_command) => vault_command_handler(vault_command),
+        Command::Backup(backup_command) => backup_command_handler(backup_command),
+    };
+
+    match result {
+        Ok(CommandOutput {
+            json: json_output,
+            text,
+        }) => {
+            if json {
+                match serde_json::to_string_pretty(&json_output) {
+                    Ok(json_output) => println!("{}", json_output),
+                    Err(e) => {
+                        eprintln!("error: {}", e);
+                        std::process::exit(1);
+                    }
+                }
+            } else {
+                println!("{}", text);
+            }
+        }
+
+        Err(e) => {
+            eprintln!("error: {}", e);
+            std::process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,230 @@
+use clap::{Args, Parser, Subcommand, ValueEnum};
+
+#[derive(Parser, Debug)]
+#[command(name = "wingedcap-cli", version, about = "Headless wingedcap client")]
+pub struct Cli {
+    /// Print machine-readable JSON instead of text
+    #[arg(long, global = true)]
+    pub json: bool,
+
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Create a new secret and store its sender
+    Create(CreateArgs),
+    /// Ping a stored sender to keep its keys locked
+    Ping(RecordArgs),
+    /// Ping every stored sender
+    PingAll,
+    /// List stored senders and receivers
+    List,
+    /// Check a stored receiver and print its message once unlocked
+    Receive(ReceiveArgs),
+    /// Import a sender or receiver from a JSON file (or stdin)
+    Import(ImportArgs),
+    /// Print a stored sender or receiver as JSON
+    Export(RecordArgs),
+    /// Print the receiver data of a stored sender to share it again
+    Share(ShareArgs),
+    /// Manage the hubs keys are requested from
+    #[command(subcommand)]
+    Hub(HubCommand),
+    /// Manage the passphrase-protected vault, unlocked with WINGEDCAP_PASSPHRASE
+    #[command(subcommand)]
+    Vault(VaultCommand),
+    /// Back up or restore every sender and receiver along with the settings
+    #[command(subcommand)]
+    Backup(BackupCommand),
+}
+
+#[derive(Args, Debug)]
+pub struct CreateArgs {
+    /// A short title to keep track of the secret
+    #[arg(long)]
+    pub label: String,
+
+    /// The message to lock, read from stdin when omitted
+    #[arg(long)]
+    pub message: Option<String>,
+
+    /// A file to lock instead of a message
+    #[arg(long, conflicts_with = "message")]
+    pub file: Option<String>,
+
+    /// Also encrypt the message with a passphrase for the recipient
+    /// (WINGEDCAP_RECIPIENT_PASSPHRASE or stdin)
+    #[arg(long)]
+    pub protect: bool,
+
+    /// Time without ping before the secret unlocks (e.g. 30s, 10m, 12h, 7d, 2w)
+    #[arg(long)]
+    pub timelock: String,
+
+    /// Number of keys to request from the hub
+    #[arg(long, default_value_t = 1)]
+    pub keys: u64,
+
+    /// Number of keys required to decrypt the secret
+    #[arg(long, default_value_t = 1)]
+    pub required: u64,
+
+    /// Comma-separated key numbers (from 1) able to decrypt the secret together,
+    /// repeat for each set (e.g. --set 1,2 --set 1,3,4), replaces --required
+    #[arg(long = "set", conflicts_with = "required")]
+    pub sets: Vec<String>,
+
+    /// Name of the hub to request keys from, the default hub when omitted
+    #[arg(long)]
+    pub hub: Option<String>,
+
+    /// Extra attempts per key after a failure, the stored setting when omitted
+    #[arg(long)]
+    pub retries: Option<u32>,
+
+    /// Milliseconds before the first retry, doubled on each following one
+    #[arg(long)]
+    pub backoff: Option<u32>,
+
+    /// Keep the keys that were set when the others still meet the required keys
+    #[arg(long)]
+    pub allow_partial: bool,
+}
+
+#[derive(Args, Debug)]
+pub struct RecordArgs {
+    /// Id (or id prefix) as printed by `list`, or exact label
+    pub id: String,
+}
+
+#[derive(Args, Debug)]
+pub struct ReceiveArgs {
+    /// Id (or id prefix) as printed by `list`, or exact label
+    pub id: String,
+
+    /// Write the message, or the attached file, here once unlocked
+    #[arg(long)]
+    pub output: Option<String>,
+
+    /// Open a passphrase-protected message (WINGEDCAP_RECIPIENT_PASSPHRASE or stdin)
+    #[arg(long)]
+    pub decrypt: bool,
+}
+
+#[derive(Args, Debug)]
+pub struct ShareArgs {
+    /// Id (or id prefix) as printed by `list`, or exact label
+    pub id: String,
+
+    /// Mark the receiver data as stored by the recipient instead of printing it
+    #[arg(long)]
+    pub confirm: bool,
+}
+
+#[derive(Args, Debug)]
+pub struct ImportArgs {
+    /// JSON file to import, read from stdin when omitted
+    pub path: Option<String>,
+
+    /// Label to use when importing bare receiver data
+    #[arg(long)]
+    pub label: Option<String>,
+
+    /// What to do when a stored receiver holds the same secret or label, fails when omitted
+    #[arg(long, value_enum)]
+    pub on_conflict: Option<OnConflict>,
+
+    /// New label for the imported receiver with `--on-conflict rename`
+    #[arg(long)]
+    pub rename_to: Option<String>,
+}
+
+#[derive(ValueEnum, Clone, Copy, Debug)]
+pub enum OnConflict {
+    /// Keep both, or the stored one alone when it holds the same secret
+    Merge,
+    /// Store the imported receiver under `--rename-to`
+    Rename,
+    /// Give the stored receiver holding the same secret the imported label,
+    /// one that only shares the label is kept alongside
+    Replace,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum HubCommand {
+    /// List configured hubs
+    List,
+    /// Add (or replace) a hub
+    Add(HubAddArgs),
+    /// Remove a hub
+    Remove(HubNameArgs),
+    /// Make a hub the default one
+    Default(HubNameArgs),
+}
+
+#[derive(Args, Debug)]
+pub struct HubAddArgs {
+    pub name: String,
+
+    pub host: String,
+
+    pub pk: String,
+
+    /// Also make it the default hub
+    #[arg(long)]
+    pub default: bool,
+}
+
+#[derive(Args, Debug)]
+pub struct HubNameArgs {
+    pub name: String,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum VaultCommand {
+    /// Tell whether the vault is disabled, locked or unlocked
+    Status,
+    /// Encrypt stored secrets with a passphrase (WINGEDCAP_PASSPHRASE or stdin), ping history
+    /// and unlock times stay unencrypted so they can be recorded while the vault is locked
+    Enable,
+    /// Store secrets as plain json again
+    Disable,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum BackupCommand {
+    /// Write a backup file
+    Export(BackupExportArgs),
+    /// Compare a backup file with the stored records and restore it
+    Restore(BackupRestoreArgs),
+}
+
+#[derive(Args, Debug)]
+pub struct BackupExportArgs {
+    /// File to write, printed to stdout when omitted
+    pub path: Option<String>,
+
+    /// Protect the backup with a passphrase (WINGEDCAP_BACKUP_PASSPHRASE or stdin)
+    #[arg(long)]
+    pub encrypt: bool,
+}
+
+#[derive(Args, Debug)]
+pub struct BackupRestoreArgs {
+    /// Backup file to restore
+    pub path: String,
+
+    /// Only print what would be restored
+    #[arg(long)]
+    pub dry_run: bool,
+
+    /// Replace stored records that conflict with the backup instead of keeping them
+    #[arg(long)]
+    pub overwrite: bool,
+
+    /// Also restore the settings saved in the backup
+    #[arg(long)]
+    pub settings: bool,
+}
@@ -0,0 +1,6 @@
+use serde_json::Value;
+
+pub struct CommandOutput {
+    pub json: Value,
+    pub text: String,
+}
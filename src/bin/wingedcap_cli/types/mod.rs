@@ -0,0 +1,5 @@
+mod cli_type;
+pub use cli_type::*;
+
+mod command_output_type;
+pub use command_output_type::*;
@@ -0,0 +1,21 @@
+use super::get_record_id;
+
+pub fn find_record<T: Clone>(
+    records: Vec<(String, T)>,
+    prefix: &str,
+    query: &str,
+    get_label: impl Fn(&T) -> String,
+) -> Result<Option<(String, T)>, String> {
+    let matches: Vec<(String, T)> = records
+        .into_iter()
+        .filter(|(storage_id, record)| {
+            get_record_id(storage_id, prefix).starts_with(query) || get_label(record) == query
+        })
+        .collect();
+
+    match matches.len() {
+        0 => Ok(None),
+        1 => Ok(matches.first().cloned()),
+        _ => Err(format!("{} records match \"{}\"", matches.len(), query)),
+    }
+}
@@ -0,0 +1,21 @@
+use wingedcap_client::types::{KeyHealth, KeyHealthStatus};
+
+// one line per unreachable key, empty when every key answered
+pub fn format_keys_health(keys_health: &[KeyHealth]) -> String {
+    keys_health
+        .iter()
+        .filter_map(
+            |KeyHealth {
+                 key,
+                 status,
+                 latency,
+             }| match status {
+                KeyHealthStatus::Unreachable(error) => Some(format!(
+                    "\n  {} unreachable after {} ms: {}",
+                    key.host, latency, error
+                )),
+                _ => None,
+            },
+        )
+        .collect()
+}
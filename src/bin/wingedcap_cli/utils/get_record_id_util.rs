@@ -0,0 +1,10 @@
+use wingedcap_client::storage::get_storage_name;
+
+pub fn get_record_id(storage_id: &str, prefix: &str) -> String {
+    let storage_name = get_storage_name(storage_id);
+
+    storage_name
+        .strip_prefix(&format!("{}_", prefix))
+        .unwrap_or(storage_name)
+        .to_string()
+}
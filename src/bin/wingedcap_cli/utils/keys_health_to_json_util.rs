@@ -0,0 +1,20 @@
+use serde_json::{json, Value};
+
+use wingedcap_client::types::{KeyHealth, KeyHealthStatus};
+
+pub fn keys_health_to_json(keys_health: &[KeyHealth]) -> Vec<Value> {
+    keys_health
+        .iter()
+        .map(|KeyHealth { key, status, latency }| match status {
+            KeyHealthStatus::Locked => {
+                json!({ "host": key.host, "state": "locked", "latency": latency })
+            }
+            KeyHealthStatus::Unlocked => {
+                json!({ "host": key.host, "state": "unlocked", "latency": latency })
+            }
+            KeyHealthStatus::Unreachable(error) => {
+                json!({ "host": key.host, "state": "unreachable", "latency": latency, "error": error })
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,26 @@
+mod get_record_id_util;
+pub use get_record_id_util::*;
+
+mod find_record_util;
+pub use find_record_util::*;
+
+mod read_input_util;
+pub use read_input_util::*;
+
+mod sender_state_to_json_util;
+pub use sender_state_to_json_util::*;
+
+mod keys_health_to_json_util;
+pub use keys_health_to_json_util::*;
+
+mod format_keys_health_util;
+pub use format_keys_health_util::*;
+
+mod read_passphrase_util;
+pub use read_passphrase_util::*;
+
+mod unlock_vault_from_env_util;
+pub use unlock_vault_from_env_util::*;
+
+mod parse_vault_set_util;
+pub use parse_vault_set_util::*;
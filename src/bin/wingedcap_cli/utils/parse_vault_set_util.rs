@@ -0,0 +1,14 @@
+use wingedcap::client::KeyIndexArray;
+
+// "1,3,4" -> [0, 2, 3]
+pub fn parse_vault_set(set: &str) -> Result<KeyIndexArray, String> {
+    set.split(',')
+        .map(|key_number| match key_number.trim().parse::<u64>() {
+            Ok(key_number) if key_number > 0 => Ok(key_number - 1),
+            _ => Err(format!(
+                "invalid key number \"{}\" in set \"{}\"",
+                key_number, set
+            )),
+        })
+        .collect()
+}
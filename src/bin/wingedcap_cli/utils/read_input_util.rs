@@ -0,0 +1,17 @@
+use std::io::Read;
+
+pub fn read_input(path: Option<&str>) -> Result<String, String> {
+    match path {
+        Some(path) => std::fs::read_to_string(path).map_err(|e| e.to_string()),
+
+        None => {
+            let mut input = String::new();
+
+            std::io::stdin()
+                .read_to_string(&mut input)
+                .map_err(|e| e.to_string())?;
+
+            Ok(input)
+        }
+    }
+}
@@ -0,0 +1,12 @@
+use super::read_input;
+
+// from the given environment variable when set, otherwise the first line of stdin
+pub fn read_passphrase(env: &str) -> Result<String, String> {
+    if let Ok(passphrase) = std::env::var(env) {
+        return Ok(passphrase);
+    }
+
+    let input = read_input(None)?;
+
+    Ok(input.lines().next().unwrap_or_default().to_string())
+}
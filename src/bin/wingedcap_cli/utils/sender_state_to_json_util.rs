@@ -0,0 +1,16 @@
+use serde_json::{json, Value};
+
+use wingedcap::client::SenderState;
+
+use wingedcap_client::types::KeyHealth;
+
+use crate::utils::keys_health_to_json;
+
+pub fn sender_state_to_json(state: &SenderState, keys_health: &[KeyHealth]) -> Value {
+    let status = match state {
+        SenderState::Locked { .. } => "locked",
+        SenderState::Unlocked { .. } => "unlocked",
+    };
+
+    json!({ "state": status, "keys": keys_health_to_json(keys_health) })
+}
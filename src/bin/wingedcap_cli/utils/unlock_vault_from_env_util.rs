@@ -0,0 +1,15 @@
+use wingedcap_client::storage::{is_vault_locked, unlock_vault};
+
+use crate::constants::PASSPHRASE_ENV;
+
+// commands reading secrets fail with "vault is locked" when the passphrase isn't provided
+pub fn unlock_vault_from_env() -> Result<(), String> {
+    if !is_vault_locked() {
+        return Ok(());
+    }
+
+    match std::env::var(PASSPHRASE_ENV) {
+        Ok(passphrase) => unlock_vault(&passphrase),
+        Err(_) => Ok(()),
+    }
+}
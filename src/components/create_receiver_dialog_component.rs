@@ -1,14 +1,24 @@
 use dioxus::prelude::*;
-use lucide_dioxus::{ClipboardCheck, ClipboardList, Info, Save, X};
+use lucide_dioxus::{
+    CircleAlert, ClipboardCheck, ClipboardList, Info, LockKeyholeOpen, QrCode, Save, X,
+};
 
 use wingedcap::client::{Receiver, ReceiverStored};
 
 use cross_clipboard::paste_from_clipboard;
 
+use crate::types::SplitShare;
+
+use crate::utils::{
+    combine_split_shares, decode_qr_image, decrypt_receiver_share, is_receiver_share,
+    is_split_share, join_qr_parts, parse_receiver_data, parse_split_share,
+};
+
 use crate::ui::{
     button::{Button, ButtonVariant},
     card::{CardContent, CardDescription, CardHeader, CardTitle},
     hovercard::{HoverCard, HoverCardContent, HoverCardTrigger},
+    input::Input,
     input_animated_label::InputAnimatedLabel,
     modal::{Modal, ModalBackground, ModalContent},
 };
@@ -54,6 +64,9 @@ impl CreateReceiverDialogState {
 pub struct CreateReceiverDialogProps {
     #[props(into)]
     on_submit: Callback<ReceiverStored>,
+    // picked up elsewhere but needs more from the user, a passphrase or the other shares
+    #[props(optional)]
+    pending_data: Option<String>,
     #[props(optional)]
     open: Option<bool>,
     #[props(optional)]
@@ -79,8 +92,20 @@ pub fn CreateReceiverDialog(props: CreateReceiverDialogProps) -> Element {
 
     let is_loaded = form_data.read().receiver.is_some();
 
+    let mut load_error: Signal<Option<String>> = use_signal(|| None);
+
+    let mut locked_share: Signal<Option<String>> = use_signal(|| None);
+    let mut share_passphrase = use_signal(String::new);
+
+    // shares of split receiver data loaded so far, until there are enough to combine them
+    let mut split_shares: Signal<Vec<SplitShare>> = use_signal(Vec::new);
+
     let mut reset_form = move || {
         form_data.set(default_form_data.clone());
+        load_error.set(None);
+        locked_share.set(None);
+        share_passphrase.set(String::new());
+        split_shares.set(vec![]);
     };
 
     use_effect(move || {
@@ -89,28 +114,151 @@ pub fn CreateReceiverDialog(props: CreateReceiverDialogProps) -> Element {
         }
     });
 
+    let mut load_receiver_data = move |mut receiver_content: String| {
+        if is_split_share(&receiver_content) {
+            let share = match parse_split_share(&receiver_content) {
+                Ok(share) => share,
+
+                Err(e) => {
+                    load_error.set(Some(e));
+                    return;
+                }
+            };
+
+            let mut shares = split_shares();
+
+            if shares
+                .first()
+                .is_some_and(|first| first.split_id != share.split_id)
+            {
+                load_error.set(Some(
+                    "this share belongs to another split of receiver data".to_string(),
+                ));
+                return;
+            }
+
+            if !shares.iter().any(|loaded| loaded.index == share.index) {
+                shares.push(share);
+            }
+
+            let threshold = shares[0].threshold as usize;
+
+            if shares.len() < threshold {
+                load_error.set(None);
+                split_shares.set(shares);
+                return;
+            }
+
+            match combine_split_shares(&shares) {
+                Ok(combined) => {
+                    split_shares.set(vec![]);
+                    receiver_content = combined;
+                }
+
+                Err(e) => {
+                    tracing::error!("error combining shares: {:?}", e);
+                    load_error.set(Some(e));
+                    return;
+                }
+            }
+        }
+
+        if is_receiver_share(&receiver_content) {
+            load_error.set(None);
+            locked_share.set(Some(receiver_content));
+            return;
+        }
+
+        match parse_receiver_data(&receiver_content) {
+            Ok((loaded_receiver, label)) => {
+                load_error.set(None);
+
+                form_data.with_mut(|data| {
+                    data.receiver = Some(loaded_receiver);
+
+                    if let Some(label) = label.filter(|_| data.label.is_empty()) {
+                        data.label = label;
+                    }
+                });
+            }
+
+            Err(e) => {
+                tracing::error!("error parsing receiver: {:?}", e);
+                load_error.set(Some(e));
+            }
+        }
+    };
+
+    let pending_data = props.pending_data.clone();
+
+    use_effect(use_reactive!(|pending_data| {
+        if let Some(pending_data) = pending_data {
+            load_receiver_data(pending_data);
+        }
+    }));
+
     let handle_paste = move |_| async move {
         let paste_result = paste_from_clipboard().await;
 
         match paste_result {
+            Ok(receiver_content) => load_receiver_data(receiver_content),
+
+            Err(e) => {
+                tracing::error!("error pasting pasted receiver: {:?}", e);
+            }
+        }
+    };
+
+    let handle_unlock_share = move |e: FormEvent| {
+        e.prevent_default();
+
+        let Some(share) = locked_share() else {
+            return;
+        };
+
+        match decrypt_receiver_share(&share, &share_passphrase()) {
             Ok(receiver_content) => {
-                let parsing_result = serde_json::from_str::<Receiver>(&receiver_content);
+                locked_share.set(None);
+                share_passphrase.set(String::new());
 
-                match parsing_result {
-                    Ok(pasted_receiver) => {
-                        form_data.with_mut(|data| {
-                            data.receiver = Some(pasted_receiver);
-                        });
-                    }
+                load_receiver_data(receiver_content);
+            }
 
-                    Err(e) => {
-                        tracing::error!("error parsing pasted receiver: {:?}", e);
-                    }
+            Err(e) => {
+                tracing::error!("error decrypting receiver share: {:?}", e);
+                load_error.set(Some(e));
+            }
+        }
+    };
+
+    // every picked image may hold one or several parts of the receiver data
+    let handle_pick_qr_code_images = move |e: FormEvent| async move {
+        let mut contents = vec![];
+
+        for file in e.files() {
+            let decoded = match file.read_bytes().await {
+                Ok(bytes) => decode_qr_image(&bytes),
+                Err(e) => Err(e.to_string()),
+            };
+
+            match decoded {
+                Ok(file_contents) => contents.extend(file_contents),
+                Err(e) => {
+                    load_error.set(Some(format!("{}: {}", file.name(), e)));
+                    return;
                 }
             }
+        }
+
+        contents.sort();
+        contents.dedup();
+
+        match join_qr_parts(contents) {
+            Ok(receiver_content) => load_receiver_data(receiver_content),
 
             Err(e) => {
-                tracing::error!("error pasting pasted receiver: {:?}", e);
+                tracing::error!("error scanning receiver: {:?}", e);
+                load_error.set(Some(e));
             }
         }
     };
@@ -156,6 +304,34 @@ pub fn CreateReceiverDialog(props: CreateReceiverDialogProps) -> Element {
                 }
 
                 CardContent { class: "w-full",
+                    if locked_share().is_some() {
+                        form {
+                            class: "flex flex-col gap-2 w-full mb-4 rounded-md border border-border px-4 py-3 text-sm",
+                            onsubmit: handle_unlock_share,
+                            span { class: "text-muted-foreground",
+                                "This receiver data is encrypted, enter the passphrase the sender gave you."
+                            }
+
+                            div { class: "flex items-center gap-2",
+                                Input {
+                                    r#type: "password",
+                                    placeholder: "Passphrase",
+                                    value: share_passphrase(),
+                                    oninput: move |e: FormEvent| share_passphrase.set(e.value()),
+                                }
+
+                                Button {
+                                    r#type: "submit",
+                                    variant: ButtonVariant::Outline,
+                                    class: "shrink-0",
+                                    disabled: share_passphrase().is_empty(),
+                                    LockKeyholeOpen { class: "size-4" }
+                                    "Unlock"
+                                }
+                            }
+                        }
+                    }
+
                     form { class: "w-full", onsubmit: move |_| handle_submit(),
                         div { class: "flex items-center gap-2 w-full",
 
@@ -190,12 +366,30 @@ pub fn CreateReceiverDialog(props: CreateReceiverDialogProps) -> Element {
                                 if is_loaded {
                                     "Data Loaded"
                                     ClipboardCheck { class: "h-4 w-4 shrink-0" }
+                                } else if !split_shares.read().is_empty() {
+                                    "Paste Next Share"
+                                    ClipboardList { class: "h-4 w-4 shrink-0" }
                                 } else {
                                     "Paste Data"
                                     ClipboardList { class: "h-4 w-4 shrink-0" }
                                 }
                             }
 
+                            if !is_loaded {
+                                label { class: "inline-flex h-9 shrink-0 cursor-pointer items-center gap-4 rounded-md border border-border px-4 text-sm font-medium hover:bg-accent hover:text-accent-foreground",
+                                    "Scan QR"
+                                    QrCode { class: "h-4 w-4 shrink-0" }
+
+                                    input {
+                                        r#type: "file",
+                                        accept: "image/png,image/jpeg",
+                                        multiple: true,
+                                        class: "hidden",
+                                        onchange: handle_pick_qr_code_images,
+                                    }
+                                }
+                            }
+
                             Button {
                                 class: "flex items-center justify-between w-max gap-4",
                                 disabled: is_form_invalid,
@@ -203,6 +397,36 @@ pub fn CreateReceiverDialog(props: CreateReceiverDialogProps) -> Element {
                                 Save { class: " h-4 w-4 shrink-0" }
                             }
                         }
+
+                        if let Some(first) = split_shares.read().first() {
+                            div { class: "flex items-center gap-2 mt-4 text-sm text-muted-foreground",
+                                Info { class: "h-4 w-4 shrink-0 text-blue-500" }
+                                span {
+                                    {
+                                        let loaded = split_shares
+                                            .read()
+                                            .iter()
+                                            .map(|share| share.index.to_string())
+                                            .collect::<Vec<String>>()
+                                            .join(", ");
+
+                                        format!(
+                                            "Share(s) {} of {} loaded, {} required. Paste or scan the next one.",
+                                            loaded,
+                                            first.total,
+                                            first.threshold,
+                                        )
+                                    }
+                                }
+                            }
+                        }
+
+                        if let Some(error) = load_error() {
+                            div { class: "flex items-center gap-2 mt-4 text-sm text-destructive",
+                                CircleAlert { class: "h-4 w-4 shrink-0" }
+                                span { "{error}" }
+                            }
+                        }
                     }
                 }
             }
@@ -1,22 +1,38 @@
-use std::cmp::{max, min};
+use std::cmp::max;
 
 use strum::IntoEnumIterator;
 
 use dioxus::prelude::*;
 
-use lucide_dioxus::{ClipboardList, Info, KeyRound, Plus, SquarePen, Trash2};
+use lucide_dioxus::{
+    CircleAlert, ClipboardList, Eye, File, Grid3X3, Info, KeyRound, LockKeyhole, Paperclip, Plus,
+    SquarePen, Trash2, TriangleAlert,
+};
 
-use wingedcap::{client::ServerWithMeta, GetServerInput};
+use wingedcap::{
+    client::{generate_standard_vault_sets, ServerWithMeta},
+    GetServerInput,
+};
 
 use cross_clipboard::paste_from_clipboard;
 
-use crate::components::KeyDetails;
-use crate::types::{Time, TimeUnit};
+use crate::constants::{
+    MAX_FILE_ATTACHMENT_SIZE, MAX_RECEIVER_CLIPBOARD_LENGTH, MAX_RECEIVER_QR_PARTS,
+    QR_PART_MAX_LENGTH,
+};
+
+use crate::components::{KeyDetails, VaultSetsEditor};
+use crate::types::{FileAttachment, FileEnvelopeHeader, SetSecretError, Settings, TimeUnit};
 
 use crate::ui::toast::{use_toast, ToastRenderer};
-use crate::utils::{get_time_unit_from_name, get_time_unit_name};
+use crate::utils::{
+    encode_file_envelope, encrypt_message_with_passphrase, estimate_receiver_size,
+    format_byte_size, get_default_required_keys, get_passphrase_envelope_length,
+    get_time_unit_from_name, get_time_unit_name, guess_mime_type, remap_vault_sets,
+    render_markdown, validate_vault_sets,
+};
 
-use crate::manager::get_server;
+use crate::manager::{get_default_hub, get_hubs, get_server};
 
 use crate::ui::select::SelectValue;
 
@@ -24,11 +40,13 @@ use crate::ui::{
     button::{Button, ButtonVariant},
     card::{CardDescription, CardHeader, CardTitle},
     hovercard::{HoverCard, HoverCardContent, HoverCardTrigger},
+    input::Input,
     input_animated_label::InputAnimatedLabel,
     modal::{Modal, ModalBackground, ModalContent, ModalTrigger},
     select::{Select, SelectContent, SelectItem, SelectTrigger},
     separator::Separator,
     spinner::Spinner,
+    textarea::TextArea,
 };
 
 use crate::views::sender_view::CreateSecretFormData;
@@ -67,7 +85,7 @@ impl CreateSecretFormDialogState {
 #[derive(Props, PartialEq, Clone)]
 pub struct CreateSecretFormDialogProps {
     #[props(into)]
-    on_submit: Callback<(CreateSecretFormData, Callback<Result<(), String>>)>,
+    on_submit: Callback<(CreateSecretFormData, Callback<Result<(), SetSecretError>>)>,
     #[props(optional)]
     open: Option<bool>,
     #[props(optional)]
@@ -89,22 +107,40 @@ pub fn CreateSecretFormDialog(props: CreateSecretFormDialogProps) -> Element {
 
     let mut is_submitting = use_signal(|| false);
     let mut is_adding_server = use_signal(|| false);
+    let mut is_previewing = use_signal(|| false);
+    let mut attached_file: Signal<Option<FileEnvelopeHeader>> = use_signal(|| None);
+
+    // encrypts the message for the recipient on top of the keys when set
+    let mut recipient_passphrase: Signal<Option<String>> = use_signal(|| None);
+    let mut confirm_recipient_passphrase = use_signal(String::new);
+
+    let hubs = get_hubs();
+
+    let mut selected_hub_name = use_signal(|| get_default_hub().ok().map(|hub| hub.name));
+
+    let settings = use_context::<Signal<Settings>>();
 
-    let default_form_data = CreateSecretFormData {
+    let default_form_data = move || CreateSecretFormData {
         label: String::new(),
         message: String::new(),
-        timelock: Time {
-            magnitude: 10,
-            unit: TimeUnit::Second,
-        },
+        timelock: settings.peek().default_timelock.clone(),
         servers: vec![],
         required_keys: 1,
+        custom_sets: None,
     };
 
-    let mut form_data = use_signal(|| default_form_data.clone());
+    let mut form_data = use_signal(default_form_data);
+
+    // failure reason of each server from the last submission, by index
+    let mut server_errors: Signal<Vec<Option<String>>> = use_signal(Vec::new);
 
     let mut reset_form = move || {
-        form_data.set(default_form_data.clone());
+        form_data.set(default_form_data());
+        server_errors.set(vec![]);
+        is_previewing.set(false);
+        attached_file.set(None);
+        recipient_passphrase.set(None);
+        confirm_recipient_passphrase.set(String::new());
     };
 
     use_effect(move || {
@@ -116,16 +152,31 @@ pub fn CreateSecretFormDialog(props: CreateSecretFormDialogProps) -> Element {
     });
 
     let mut handle_add_server = move |server: ServerWithMeta| {
+        server_errors.set(vec![]);
+
         form_data.with_mut(|data| {
             data.servers.push(server);
+            data.required_keys = get_default_required_keys(
+                data.servers.len() as u64,
+                settings.peek().default_required_keys_ratio,
+            );
         });
     };
 
     let handle_add_server_from_hub = move || {
         spawn(async move {
+            let selected_hub = get_hubs()
+                .into_iter()
+                .find(|hub| Some(hub.name.clone()) == selected_hub_name());
+
+            let Some(hub) = selected_hub else {
+                toast.error("No hub configured");
+                return;
+            };
+
             is_adding_server.set(true);
 
-            let server = get_server(&GetServerInput {}).await;
+            let server = get_server(&hub, &GetServerInput {}).await;
 
             if let Ok(server) = server {
                 handle_add_server(server);
@@ -152,36 +203,184 @@ pub fn CreateSecretFormDialog(props: CreateSecretFormDialogProps) -> Element {
     };
 
     let mut handle_remove_server = move |index: usize| {
+        server_errors.with_mut(|errors| {
+            if index < errors.len() {
+                errors.remove(index);
+            }
+        });
+
         form_data.with_mut(|data| {
             data.servers.remove(index);
-            data.required_keys = max(1, min(data.required_keys, data.servers.len() as u64));
+            data.custom_sets = data.custom_sets.take().map(|sets| {
+                let kept_indexes: Vec<u64> = (0..=data.servers.len() as u64)
+                    .filter(|kept_index| *kept_index != index as u64)
+                    .collect();
+
+                remap_vault_sets(sets, &kept_indexes)
+            });
+            data.required_keys = get_default_required_keys(
+                data.servers.len() as u64,
+                settings.peek().default_required_keys_ratio,
+            );
         });
     };
 
-    let handle_submitted = move |result: Result<(), String>| {
+    let handle_pick_attachment = move |e: FormEvent| async move {
+        let Some(file) = e.files().into_iter().next() else {
+            return;
+        };
+
+        if file.size() > MAX_FILE_ATTACHMENT_SIZE {
+            toast.error(format!(
+                "The file is too large, attachments are limited to {}",
+                format_byte_size(MAX_FILE_ATTACHMENT_SIZE as usize)
+            ));
+            return;
+        }
+
+        let bytes = match file.read_bytes().await {
+            Ok(bytes) => bytes.to_vec(),
+
+            Err(e) => {
+                tracing::error!("error reading attachment: {:?}", e);
+                toast.error("Failed to read the file");
+                return;
+            }
+        };
+
+        let name = file.name();
+
+        let mime = file
+            .content_type()
+            .filter(|mime| !mime.is_empty())
+            .unwrap_or_else(|| guess_mime_type(&name));
+
+        let attachment = FileAttachment { name, mime, bytes };
+
+        // the envelope becomes the message, so the file goes through the usual secret creation
+        match encode_file_envelope(&attachment) {
+            Ok(envelope) => {
+                attached_file.set(Some(FileEnvelopeHeader {
+                    name: attachment.name,
+                    mime: attachment.mime,
+                    size: attachment.bytes.len() as u64,
+                    compressed: false,
+                }));
+
+                is_previewing.set(false);
+
+                form_data.with_mut(|data| data.message = envelope);
+            }
+
+            Err(e) => {
+                tracing::error!("error encoding attachment: {:?}", e);
+                toast.error(format!("Failed to attach the file: {}", e));
+            }
+        }
+    };
+
+    let handle_toggle_custom_sets = move |_| {
+        form_data.with_mut(|data| {
+            data.custom_sets = match data.custom_sets {
+                Some(_) => None,
+                None if data.servers.is_empty() => Some(vec![]),
+                None => Some(generate_standard_vault_sets(
+                    data.servers.len() as u64,
+                    data.required_keys,
+                )),
+            };
+        });
+    };
+
+    let handle_submitted = move |result: Result<(), SetSecretError>| {
         is_submitting.set(false);
 
-        if result.is_err() {
-            toast.error("Failed to create secret");
+        if let Err(e) = result {
+            server_errors.set(
+                e.outcomes
+                    .into_iter()
+                    .map(|outcome| outcome.error)
+                    .collect(),
+            );
+
+            toast.error(format!("Failed to create secret: {}", e.message));
         }
     };
 
     let handle_submit = move || async move {
         is_submitting.set(true);
 
-        let form_data = form_data.read().clone();
+        let mut form_data = form_data.read().clone();
+
+        if let Some(passphrase) = recipient_passphrase() {
+            match encrypt_message_with_passphrase(&form_data.message, &passphrase) {
+                Ok(encrypted_message) => form_data.message = encrypted_message,
+
+                Err(e) => {
+                    is_submitting.set(false);
+                    toast.error(format!("Failed to encrypt the message: {}", e));
+                    return;
+                }
+            }
+        }
 
         props
             .on_submit
             .call((form_data, Callback::new(handle_submitted)));
     };
 
-    let is_form_invalid = form_data().label.is_empty()
+    let message_chars = form_data().message.chars().count();
+    let message_bytes = form_data().message.len();
+
+    // what the recipient will have to copy or scan, only known once there are keys
+    let receiver_size = (!form_data().servers.is_empty()).then(|| {
+        let sets = form_data().custom_sets.unwrap_or_else(|| {
+            generate_standard_vault_sets(
+                form_data().servers.len() as u64,
+                form_data().required_keys,
+            )
+        });
+
+        let message_length = match recipient_passphrase() {
+            Some(_) => get_passphrase_envelope_length(message_bytes),
+            None => message_bytes,
+        };
+
+        estimate_receiver_size(message_length, &form_data().servers, &sets)
+    });
+
+    let receiver_qr_parts = receiver_size
+        .map(|receiver_size| receiver_size.div_ceil(QR_PART_MAX_LENGTH))
+        .unwrap_or_default();
+
+    let receiver_size_warning = match receiver_size {
+        Some(receiver_size) if receiver_size > MAX_RECEIVER_CLIPBOARD_LENGTH => Some(
+            "The receiver data is too large to share through the clipboard or QR codes reliably, consider a shorter message or fewer key sets.".to_string(),
+        ),
+        Some(_) if receiver_qr_parts > MAX_RECEIVER_QR_PARTS => Some(format!(
+            "The receiver data needs {} QR codes to be scanned, consider sharing it through the clipboard instead.",
+            receiver_qr_parts
+        )),
+        _ => None,
+    };
+
+    let is_recipient_passphrase_invalid = match recipient_passphrase() {
+        Some(passphrase) => passphrase.is_empty() || passphrase != confirm_recipient_passphrase(),
+        None => false,
+    };
+
+    let is_form_invalid = is_recipient_passphrase_invalid
+        || form_data().label.is_empty()
         || form_data().message.is_empty()
         || form_data().timelock.magnitude == 0
         || form_data().servers.is_empty()
-        || form_data().required_keys == 0
-        || form_data().required_keys > form_data().servers.len() as u64;
+        || match form_data().custom_sets {
+            Some(sets) => validate_vault_sets(&sets, form_data().servers.len() as u64).is_err(),
+            None => {
+                form_data().required_keys == 0
+                    || form_data().required_keys > form_data().servers.len() as u64
+            }
+        };
 
     rsx! {
         Modal {
@@ -297,12 +496,148 @@ pub fn CreateSecretFormDialog(props: CreateSecretFormDialogProps) -> Element {
                         }
 
                         // Message
-                        InputAnimatedLabel {
-                            label: "Message",
-                            value: "{form_data().message}",
-                            oninput: move |e: FormEvent| {
-                                form_data.with_mut(|data| data.message = e.value());
-                            },
+                        div { class: "flex flex-col gap-1.5",
+                            div { class: "flex items-center justify-between gap-2",
+                                label { class: "text-muted-foreground text-sm", "Message" }
+
+                                div { class: "flex items-center gap-1",
+                                    label { class: "inline-flex h-6 cursor-pointer items-center gap-1.5 rounded-md px-2 text-xs font-medium hover:bg-accent hover:text-accent-foreground",
+                                        Paperclip { class: "size-3.5" }
+                                        "Attach file"
+
+                                        input {
+                                            r#type: "file",
+                                            class: "hidden",
+                                            onchange: handle_pick_attachment,
+                                        }
+                                    }
+
+                                    if attached_file().is_none() {
+                                        Button {
+                                            r#type: "button",
+                                            variant: ButtonVariant::Ghost,
+                                            class: "h-6 gap-1.5 px-2 text-xs",
+                                            onclick: move |_| is_previewing.toggle(),
+                                            if is_previewing() {
+                                                SquarePen { class: "size-3.5" }
+                                                "Edit"
+                                            } else {
+                                                Eye { class: "size-3.5" }
+                                                "Preview"
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+
+                            if let Some(file) = attached_file() {
+                                div { class: "flex items-center gap-3 rounded-md border border-input px-2 py-1.5 text-sm",
+                                    File { class: "size-6 shrink-0 stroke-primary" }
+
+                                    div { class: "grid min-w-0 grow",
+                                        span { class: "truncate font-medium", "{file.name}" }
+                                        span { class: "text-xs text-muted-foreground",
+                                            "{file.mime}, {format_byte_size(file.size as usize)}"
+                                        }
+                                    }
+
+                                    Button {
+                                        r#type: "button",
+                                        variant: ButtonVariant::Ghost,
+                                        class: "shrink-0 px-0",
+                                        onclick: move |_| {
+                                            attached_file.set(None);
+                                            form_data.with_mut(|data| data.message = String::new());
+                                        },
+                                        Trash2 { class: "text-destructive" }
+                                    }
+                                }
+                            } else if is_previewing() {
+                                div {
+                                    class: "min-h-32 max-h-[40vh] overflow-auto rounded-md border border-input px-2 py-1.5 text-sm space-y-2 break-words [&_h1]:text-lg [&_h1]:font-semibold [&_h2]:font-semibold [&_h3]:font-semibold [&_ul]:list-disc [&_ul]:pl-5 [&_ol]:list-decimal [&_ol]:pl-5 [&_code]:font-mono [&_pre]:whitespace-pre-wrap [&_a]:underline [&_blockquote]:border-l-2 [&_blockquote]:pl-3",
+                                    dangerous_inner_html: render_markdown(&form_data().message),
+                                }
+                            } else {
+                                TextArea {
+                                    class: "min-h-32 max-h-[40vh] resize-y",
+                                    rows: 6,
+                                    placeholder: "Letters, credentials, instructions... Markdown is supported.",
+                                    value: "{form_data().message}",
+                                    oninput: move |e: FormEvent| {
+                                        form_data.with_mut(|data| data.message = e.value());
+                                    },
+                                }
+                            }
+
+                            div { class: "flex flex-wrap items-center justify-between gap-x-4 text-xs text-muted-foreground",
+                                if let Some(file) = attached_file() {
+                                    span { "{format_byte_size(file.size as usize)} file, {format_byte_size(message_bytes)} once encoded" }
+                                } else {
+                                    span { "{message_chars} characters, {format_byte_size(message_bytes)}" }
+                                }
+
+                                if let Some(receiver_size) = receiver_size {
+                                    span { "receiver data ~{format_byte_size(receiver_size)}, {receiver_qr_parts} QR code(s)" }
+                                }
+                            }
+
+                            if let Some(warning) = receiver_size_warning {
+                                div { class: "flex items-center gap-4 rounded-md border border-orange-500 bg-orange-500/10 px-4 py-2 text-sm text-orange-500",
+                                    TriangleAlert { class: "h-4 w-4 shrink-0" }
+                                    span { "{warning}" }
+                                }
+                            }
+                        }
+                    }
+
+                    // Recipient passphrase
+                    div { class: "flex flex-col gap-2",
+                        div { class: "flex items-center gap-2",
+                            label { class: "text-muted-foreground text-sm text-nowrap", "Recipient passphrase" }
+
+                            Separator { class: "w-auto grow" }
+
+                            Button {
+                                r#type: "button",
+                                variant: ButtonVariant::Ghost,
+                                class: "px-0",
+                                onclick: move |_| {
+                                    recipient_passphrase
+                                        .set(match recipient_passphrase() {
+                                            Some(_) => None,
+                                            None => Some(String::new()),
+                                        });
+                                    confirm_recipient_passphrase.set(String::new());
+                                },
+                                LockKeyhole { class: if recipient_passphrase().is_some() { "stroke-primary" } else { "" } }
+                            }
+
+                            HoverCard {
+                                HoverCardTrigger {
+                                    Info { class: "text-blue-500" }
+                                }
+
+                                HoverCardContent { class: "max-w-50",
+                                    "Optional. The message can only be read with this passphrase once unlocked, give it to your recipient out of band. It can't be recovered if lost."
+                                }
+                            }
+                        }
+
+                        if let Some(passphrase) = recipient_passphrase() {
+                            div { class: "grid grid-cols-2 gap-2",
+                                Input {
+                                    r#type: "password",
+                                    placeholder: "Passphrase",
+                                    value: passphrase,
+                                    oninput: move |e: FormEvent| recipient_passphrase.set(Some(e.value())),
+                                }
+                                Input {
+                                    r#type: "password",
+                                    placeholder: "Confirm passphrase",
+                                    value: confirm_recipient_passphrase(),
+                                    oninput: move |e: FormEvent| confirm_recipient_passphrase.set(e.value()),
+                                }
+                            }
                         }
                     }
 
@@ -337,6 +672,18 @@ pub fn CreateSecretFormDialog(props: CreateSecretFormDialogProps) -> Element {
                                                     KeyDetails { key_with_meta: server.clone() }
                                                 }
 
+                                                if let Some(Some(error)) = server_errors().get(index) {
+                                                    HoverCard {
+                                                        HoverCardTrigger { class: "flex items-center shrink-0",
+                                                            CircleAlert { class: "text-destructive" }
+                                                        }
+
+                                                        HoverCardContent { class: "max-w-50 break-words",
+                                                            "{error}"
+                                                        }
+                                                    }
+                                                }
+
                                                 Button {
                                                     r#type: "button",
                                                     variant: ButtonVariant::Ghost,
@@ -380,6 +727,25 @@ pub fn CreateSecretFormDialog(props: CreateSecretFormDialogProps) -> Element {
                                         Plus { class: "" }
                                         span { "Add key" }
                                     }
+
+                                    if hubs.len() > 1 {
+                                        Select {
+                                            class: "h-6 w-32",
+                                            value: "{selected_hub_name().unwrap_or_default()}",
+                                            on_value_change: move |value: String| {
+                                                selected_hub_name.set(Some(value));
+                                            },
+
+                                            SelectTrigger { SelectValue {} }
+                                            SelectContent { class: "",
+                                                for hub in hubs.iter() {
+                                                    SelectItem { value: "{hub.name}",
+                                                        span { "{hub.name}" }
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
                                 }
 
                                 Button {
@@ -396,6 +762,11 @@ pub fn CreateSecretFormDialog(props: CreateSecretFormDialogProps) -> Element {
 
                         // Required Keys
                         div { class: "flex grow items-center gap-2 mt-4",
+                            if form_data().custom_sets.is_some() {
+                                label { class: "text-muted-foreground mb-0 w-max text-sm text-nowrap",
+                                    "Custom sets"
+                                }
+                            } else {
                             div { class: "flex items-center gap-2",
                                 label { class: "text-muted-foreground mb-0 w-max grow text-sm text-nowrap",
                                     "Required keys"
@@ -439,14 +810,40 @@ pub fn CreateSecretFormDialog(props: CreateSecretFormDialogProps) -> Element {
                                 }
                             }
 
+                            }
+
                             Separator { class: "w-auto grow" } // Separator
 
+                            Button {
+                                r#type: "button",
+                                variant: ButtonVariant::Ghost,
+                                class: "px-0",
+                                onclick: handle_toggle_custom_sets,
+                                Grid3X3 { class: if form_data().custom_sets.is_some() { "stroke-primary" } else { "" } }
+                            }
+
                             HoverCard {
                                 HoverCardTrigger {
                                     Info { class: "text-blue-500" }
                                 }
                                 HoverCardContent { class: "max-w-50",
-                                    "The minimum number of keys required to decrypt your secret"
+                                    if form_data().custom_sets.is_some() {
+                                        "Each set is a group of keys that is enough on its own to decrypt your secret"
+                                    } else {
+                                        "The minimum number of keys required to decrypt your secret. Use the grid button to pick the key sets yourself."
+                                    }
+                                }
+                            }
+                        }
+
+                        if let Some(sets) = form_data().custom_sets {
+                            div { class: "mt-2",
+                                VaultSetsEditor {
+                                    keys: form_data().servers,
+                                    sets,
+                                    on_change: move |sets| {
+                                        form_data.with_mut(|data| data.custom_sets = Some(sets));
+                                    },
                                 }
                             }
                         }
@@ -0,0 +1,47 @@
+use std::collections::HashMap;
+
+use dioxus::prelude::*;
+
+use crate::constants::UNLOCK_WARNING_TOAST_DURATION;
+
+use crate::types::Heartbeat;
+
+use crate::ui::{
+    button::{Button, ButtonVariant},
+    toast::{use_toast, ToastRenderer},
+};
+
+use crate::utils::{request_ping, use_heartbeat_scheduler, use_unlock_warnings};
+
+// renders nothing, it must sit below the Toaster to toast unlock warnings,
+// and outside of the tabs and the unlock view to keep pinging whatever is shown
+#[component]
+pub fn HeartbeatScheduler() -> Element {
+    let mut toast = use_toast();
+
+    let heartbeats = use_context::<Signal<HashMap<String, Heartbeat>>>();
+
+    use_heartbeat_scheduler();
+
+    use_unlock_warnings(Callback::new(
+        move |(sender_id, message): (String, String)| {
+            toast.warning(
+                rsx! {
+                    div { class: "flex items-center justify-between gap-4",
+                        p { "{message}" }
+
+                        Button {
+                            variant: ButtonVariant::Outline,
+                            class: "h-8 shrink-0 text-foreground",
+                            onclick: move |_| request_ping(heartbeats, &sender_id),
+                            "Ping now"
+                        }
+                    }
+                },
+                UNLOCK_WARNING_TOAST_DURATION,
+            );
+        },
+    ));
+
+    rsx! {}
+}
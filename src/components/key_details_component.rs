@@ -4,6 +4,8 @@ use crate::types::*;
 
 use wingedcap::client::{ServerMeta, ServerWithMeta};
 
+use crate::components::KeyHealthIndicator;
+
 use crate::ui::hovercard::{HoverCard, HoverCardContent, HoverCardTrigger};
 use crate::ui::separator::Separator;
 
@@ -13,6 +15,8 @@ use lucide_dioxus::{Fingerprint, HatGlasses, IdCard, Link, MapPin, MapPinOff, Se
 pub struct KeyDetailsProps {
     #[props(into)]
     key_with_meta: ServerWithMeta,
+    #[props(optional)]
+    health: Option<KeyHealth>,
 }
 
 #[component]
@@ -53,6 +57,10 @@ pub fn KeyDetails(props: KeyDetailsProps) -> Element {
             Separator { class: "w-auto grow" }
 
             div { class: "flex items-center gap-1",
+                if let Some(health) = props.health {
+                    KeyHealthIndicator { health }
+                }
+
                 HoverCard {
                     HoverCardTrigger {
                         Fingerprint { class: "" }
@@ -0,0 +1,56 @@
+use dioxus::prelude::*;
+
+use lucide_dioxus::{Wifi, WifiOff};
+
+use crate::types::{KeyHealth, KeyHealthStatus, Side};
+
+use crate::ui::hovercard::{HoverCard, HoverCardContent, HoverCardTrigger};
+
+#[derive(Props, PartialEq, Clone)]
+pub struct KeyHealthIndicatorProps {
+    health: KeyHealth,
+    #[props(optional)]
+    class: Option<String>,
+}
+
+#[component]
+pub fn KeyHealthIndicator(
+    KeyHealthIndicatorProps { health, class }: KeyHealthIndicatorProps,
+) -> Element {
+    let class = class.unwrap_or_default();
+
+    let KeyHealth {
+        key,
+        status,
+        latency,
+    } = health;
+
+    let status_description = match status.clone() {
+        KeyHealthStatus::Locked => format!("locked, answered in {latency} ms"),
+        KeyHealthStatus::Unlocked => format!("unlocked, answered in {latency} ms"),
+        KeyHealthStatus::Unreachable(_) => format!("unreachable, failed after {latency} ms"),
+    };
+
+    rsx! {
+        HoverCard {
+            HoverCardTrigger { class: "flex items-center",
+                if let KeyHealthStatus::Unreachable(_) = status {
+                    WifiOff { class: "stroke-destructive {class}" }
+                } else {
+                    Wifi { class: "stroke-green-500 {class}" }
+                }
+            }
+            HoverCardContent { class: "max-w-64 break-all", side: Side::Top,
+                h3 { class: "font-semibold mb-1.5 text-xs uppercase tracking-wide",
+                    "Health"
+                }
+                p { class: "text-xs", "{key.host}" }
+                p { class: "text-xs text-muted-foreground", "{status_description}" }
+
+                if let KeyHealthStatus::Unreachable(error) = status {
+                    p { class: "mt-1.5 text-xs text-destructive", "{error}" }
+                }
+            }
+        }
+    }
+}
@@ -24,3 +24,30 @@ pub use sender_details_dialog_component::*;
 
 pub mod key_details_component;
 pub use key_details_component::*;
+
+pub mod key_health_indicator_component;
+pub use key_health_indicator_component::*;
+
+pub mod unreadable_record_component;
+pub use unreadable_record_component::*;
+
+pub mod receiver_conflict_dialog_component;
+pub use receiver_conflict_dialog_component::*;
+
+pub mod receiver_details_dialog_component;
+pub use receiver_details_dialog_component::*;
+
+pub mod vault_sets_editor_component;
+pub use vault_sets_editor_component::*;
+
+pub mod restore_backup_dialog_component;
+pub use restore_backup_dialog_component::*;
+
+pub mod qr_code_parts_component;
+pub use qr_code_parts_component::*;
+
+pub mod receiver_poller_component;
+pub use receiver_poller_component::*;
+
+pub mod heartbeat_scheduler_component;
+pub use heartbeat_scheduler_component::*;
@@ -0,0 +1,64 @@
+use dioxus::prelude::*;
+
+use lucide_dioxus::{ChevronLeft, ChevronRight};
+
+use crate::ui::button::{Button, ButtonVariant};
+
+use crate::utils::{render_qr_svg, split_qr_parts};
+
+#[derive(Props, PartialEq, Clone)]
+pub struct QrCodePartsProps {
+    payload: String,
+}
+
+// one code at a time, large payloads are split over several codes to scan in a row
+#[component]
+pub fn QrCodeParts(QrCodePartsProps { payload }: QrCodePartsProps) -> Element {
+    let mut part_index = use_signal(|| 0);
+
+    let parts = split_qr_parts(&payload);
+
+    let total = parts.len();
+
+    // the payload may have changed to fewer parts since
+    let index = part_index().min(total - 1);
+
+    let svg = render_qr_svg(&parts[index]);
+
+    rsx! {
+        div { class: "flex flex-col items-center gap-2",
+            match svg {
+                Ok(svg) => rsx! {
+                    div { class: "size-64 rounded-md bg-white p-2 [&>svg]:size-full", dangerous_inner_html: svg }
+                },
+                Err(e) => rsx! {
+                    span { class: "text-sm text-destructive", "{e}" }
+                },
+            }
+
+            if total > 1 {
+                div { class: "flex items-center gap-2 text-sm",
+                    Button {
+                        r#type: "button",
+                        variant: ButtonVariant::Ghost,
+                        class: "size-8 px-0",
+                        disabled: index == 0,
+                        onclick: move |_| part_index.set(index - 1),
+                        ChevronLeft { class: "" }
+                    }
+
+                    span { class: "text-muted-foreground", "Part {index + 1} of {total}" }
+
+                    Button {
+                        r#type: "button",
+                        variant: ButtonVariant::Ghost,
+                        class: "size-8 px-0",
+                        disabled: index + 1 == total,
+                        onclick: move |_| part_index.set(index + 1),
+                        ChevronRight { class: "" }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,147 @@
+use dioxus::prelude::*;
+use lucide_dioxus::{Merge, PenLine, Replace, X};
+
+use wingedcap::client::ReceiverStored;
+
+use crate::storage::get_receiver_id;
+
+use crate::types::ConflictResolution;
+
+use crate::ui::{
+    button::{Button, ButtonVariant},
+    card::{CardContent, CardDescription, CardHeader, CardTitle},
+    input_animated_label::InputAnimatedLabel,
+    modal::{Modal, ModalBackground, ModalContent},
+};
+
+#[derive(Props, PartialEq, Clone)]
+pub struct ReceiverConflictDialogProps {
+    // the receiver being imported and the stored one it conflicts with
+    conflict: Option<(ReceiverStored, ReceiverStored)>,
+    #[props(into)]
+    on_resolve: Callback<(ReceiverStored, ConflictResolution)>,
+    #[props(into)]
+    on_cancel: Callback<()>,
+}
+
+#[component]
+pub fn ReceiverConflictDialog(props: ReceiverConflictDialogProps) -> Element {
+    let mut new_label = use_signal(String::new);
+
+    let conflict = props.conflict.clone();
+
+    use_effect(use_reactive!(|conflict| {
+        if let Some((incoming, _)) = conflict {
+            new_label.set(format!("{} (2)", incoming.label));
+        }
+    }));
+
+    let Some((incoming, existing)) = props.conflict.clone() else {
+        return rsx! {};
+    };
+
+    let is_same_secret = matches!(
+        (get_receiver_id(&incoming), get_receiver_id(&existing)),
+        (Ok(incoming_id), Ok(existing_id)) if incoming_id == existing_id
+    );
+
+    let description = if is_same_secret {
+        format!(
+            "This secret is already stored as \"{}\". Keep the stored one, store it again under another label, or replace it with the new one.",
+            existing.label
+        )
+    } else {
+        format!(
+            "Another secret is already stored as \"{}\". Keep both, or give the new one another label.",
+            existing.label
+        )
+    };
+
+    let is_new_label_invalid = new_label.read().is_empty() || *new_label.read() == existing.label;
+
+    let resolve = move |resolution: ConflictResolution| {
+        props.on_resolve.call((incoming.clone(), resolution));
+    };
+
+    let handle_merge = resolve.clone();
+    let handle_rename = resolve.clone();
+    let handle_replace = resolve;
+
+    rsx! {
+        Modal {
+            open: true,
+            onopenchange: move |open: bool| {
+                if !open {
+                    props.on_cancel.call(());
+                }
+            },
+
+            ModalBackground {}
+
+            ModalContent { class: "",
+                div {
+                    onclick: move |_| props.on_cancel.call(()),
+                    class: "absolute top-2 right-2 cursor-pointer z-10",
+                    X { class: "stroke-destructive h-4 w-4 stroke-3" }
+                }
+
+                CardHeader { class: "mb-6",
+                    CardTitle { "Conflicting Secret" }
+
+                    CardDescription { "{description}" }
+                }
+
+                CardContent { class: "flex flex-col gap-4 w-full",
+                    Button {
+                        variant: ButtonVariant::Outline,
+                        onclick: move |_| handle_merge(ConflictResolution::Merge),
+                        class: "flex items-center justify-between w-full gap-4",
+                        if is_same_secret {
+                            "Keep stored"
+                        } else {
+                            "Keep both"
+                        }
+                        Merge { class: "h-4 w-4 shrink-0" }
+                    }
+
+                    form {
+                        class: "flex items-center gap-2 w-full",
+                        onsubmit: move |_| {
+                            if !is_new_label_invalid {
+                                handle_rename(ConflictResolution::Rename(new_label()));
+                            }
+                        },
+
+                        InputAnimatedLabel {
+                            label: "New label",
+                            value: new_label(),
+                            oninput: move |e: FormEvent| new_label.set(e.value()),
+                            container_class: "grow",
+                        }
+
+                        Button {
+                            r#type: "submit",
+                            variant: ButtonVariant::Outline,
+                            disabled: is_new_label_invalid,
+                            class: "flex items-center justify-between w-max gap-4",
+                            "Rename"
+                            PenLine { class: "h-4 w-4 shrink-0" }
+                        }
+                    }
+
+                    // replacing only ever applies to the very same secret, which keeps
+                    // its unlock record and saved message under the new label
+                    if is_same_secret {
+                        Button {
+                            variant: ButtonVariant::Outline,
+                            onclick: move |_| handle_replace(ConflictResolution::Replace),
+                            class: "flex items-center justify-between w-full gap-4",
+                            "Replace"
+                            Replace { class: "h-4 w-4 shrink-0" }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
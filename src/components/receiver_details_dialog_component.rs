@@ -0,0 +1,306 @@
+use dioxus::prelude::*;
+
+use lucide_dioxus::{
+    ArrowRight, Info, KeyRound, Layers, LockKeyhole, LockKeyholeOpen, Pencil, Save, Shield,
+    TableOfContents, X,
+};
+use wingedcap::client::{
+    get_vault_conf, ReceiverKeySet, ReceiverState, ReceiverStored, ServerWithMeta, VaultConf,
+};
+
+use crate::ui::button::{Button, ButtonVariant};
+use crate::ui::input::Input;
+use crate::ui::modal::{Modal, ModalBackground, ModalContent, ModalTrigger};
+use crate::ui::separator::Separator;
+use crate::ui::spinner::Spinner;
+
+use crate::ui::card::{CardContent, CardDescription, CardHeader, CardTitle};
+
+use crate::components::KeyDetails;
+
+use crate::types::KeyHealth;
+
+use crate::utils::time::format_unix_time;
+
+#[derive(PartialEq, Props, Clone)]
+pub struct ReceiverDetailsDialogProps {
+    pub secret: ReceiverStored,
+    pub state: Option<ReceiverState>,
+    pub keys_health: Option<Vec<KeyHealth>>,
+    // first time the secret was seen unlocked
+    pub unlocked_at: Option<i64>,
+    pub on_relabel: EventHandler<String>,
+}
+
+#[component]
+pub fn ReceiverDetailsDialog(
+    ReceiverDetailsDialogProps {
+        secret,
+        state,
+        keys_health,
+        unlocked_at,
+        on_relabel,
+    }: ReceiverDetailsDialogProps,
+) -> Element {
+    let mut edited_label: Signal<Option<String>> = use_signal(|| None);
+
+    let label = secret.label;
+
+    let keys = secret.keys;
+
+    let sets = secret.sets;
+
+    let num_keys = keys.len();
+
+    let num_sets = sets.len();
+
+    // None while the state is being fetched
+    let is_set_unlocked = |set: &ReceiverKeySet| match &state {
+        None => None,
+        Some(ReceiverState::Locked { .. }) => Some(false),
+        Some(ReceiverState::Unlocked { unlocked_sets, .. }) => Some(
+            unlocked_sets
+                .iter()
+                .any(|unlocked_set| unlocked_set.encrypted_data == set.data),
+        ),
+    };
+
+    let sets_status: Vec<(Vec<u64>, Option<bool>)> = sets
+        .iter()
+        .map(|set| (set.keys.clone(), is_set_unlocked(set)))
+        .collect();
+
+    let num_unlocked_sets = sets_status
+        .iter()
+        .filter(|(_, is_unlocked)| *is_unlocked == Some(true))
+        .count();
+
+    let status_description = match state {
+        None => "Checking...".to_string(),
+        Some(ReceiverState::Locked { .. }) => "Locked".to_string(),
+        Some(ReceiverState::Unlocked { .. }) => {
+            format!("Unlocked ({num_unlocked_sets} of {num_sets} sets)")
+        }
+    };
+
+    let vault_conf = get_vault_conf(sets.iter().map(|set| set.keys.clone()).collect());
+
+    let vault_conf_description = match vault_conf {
+        VaultConf::Standard { total, required } => {
+            format!("Standard {required} of {total}")
+        }
+        VaultConf::Custom => "Custom".to_string(),
+    };
+
+    let label_for_render = label.clone();
+
+    let label_for_handle_enable_editing = label.clone();
+
+    let handle_enable_editing_lable = move || {
+        edited_label.set(Some(label_for_handle_enable_editing.clone()));
+    };
+
+    let handle_label_input_change = move |current_label: String| {
+        edited_label.set(Some(current_label));
+    };
+
+    let handle_cancel_editing_label = move || {
+        edited_label.set(None);
+    };
+
+    let handle_save_label = move |new_label: String| {
+        on_relabel(new_label);
+
+        edited_label.set(None);
+    };
+
+    rsx! {
+        Modal {
+            ModalTrigger { class: "px-0 pr-0 flex items-center justify-center border-none shadow-none",
+                Info { class: "stroke-primary" }
+            }
+
+            ModalBackground {}
+
+            ModalContent { class: "sm:min-w-md md:min-w-lg",
+                CardHeader { class: "mb-4",
+                    CardTitle { class: "",
+                        form {
+                            class: "flex items-center gap-1 relative",
+                            onsubmit: move |_| {
+                                if let Some(current_value) = edited_label() {
+                                    let mut handle_save_label = handle_save_label;
+                                    handle_save_label(current_value.clone())
+                                }
+                            },
+                            onreset: move |e: FormEvent| {
+                                e.prevent_default();
+                                let mut handle_cancel_editing_label = handle_cancel_editing_label;
+                                handle_cancel_editing_label()
+                            },
+
+                            div { class: "flex items-center gap-1 relative",
+                                Input {
+                                    class: "text-xl pl-1 -ml-1 disabled:opacity-100 disabled:cursor-text disabled:border-transparent",
+                                    value: edited_label().unwrap_or(label),
+                                    disabled: edited_label().is_none(),
+                                    oninput: move |e: FormEvent| {
+                                        let mut handle_label_input_change = handle_label_input_change;
+
+                                        handle_label_input_change(e.value());
+                                    },
+                                }
+
+                                if edited_label().is_some() {
+                                    Button {
+                                        r#type: "reset",
+                                        variant: ButtonVariant::Ghost,
+                                        class: "px-0 absolute right-1.5 size-7",
+                                        X { class: "stroke-destructive" }
+                                    }
+                                }
+                            }
+
+                            if edited_label().is_none() {
+                                Button {
+                                    r#type: "button",
+                                    variant: ButtonVariant::Ghost,
+                                    class: "px-0 shrink-0",
+                                    onclick: move |_| {
+                                        let mut handle_enable_editing_lable = handle_enable_editing_lable.clone();
+                                        handle_enable_editing_lable()
+                                    },
+                                    Pencil { class: "" }
+                                }
+                            }
+
+                            if let Some(current_value) = edited_label() {
+                                Button {
+                                    r#type: "submit",
+                                    variant: ButtonVariant::Ghost,
+                                    class: "px-0 shrink-0",
+                                    disabled: current_value == label_for_render,
+                                    Save { class: "stroke-primary" }
+                                }
+                            }
+                        }
+
+
+                    }
+                    CardDescription { "Detailed configuration and management" }
+                }
+
+                CardContent {
+                    div { class: "",
+                        div { class: "relative mt-8 mb-5 flex items-center justify-center",
+                            Separator { class: "w-full" }
+
+                            div { class: "text-muted-foreground bg-background absolute flex items-center gap-2 px-4 text-sm font-semibold",
+                                span { class: "tracking-wider", "General" }
+                                TableOfContents { class: "" }
+                            }
+                        }
+
+                        div { class: "grid grid-cols-[auto_1fr] gap-x-4 gap-y-1 w-max *:odd:font-medium text-sm",
+                            span { class: "", "No. keys" }
+                            span { class: "", "{num_keys} (see below)" }
+
+                            span { class: "", "Vault type" }
+                            span { class: "", "{vault_conf_description}" }
+
+                            span { class: "", "Status" }
+                            span { class: "", "{status_description}" }
+
+                            if let Some(unlocked_at) = unlocked_at {
+                                span { class: "", "Unlocked at" }
+                                span { class: "", "{format_unix_time(unlocked_at)}" }
+                            }
+                        }
+                    }
+
+                    div { class: "",
+                        div { class: "relative mt-8 mb-5 flex items-center justify-center",
+                            Separator { class: "w-full" }
+
+                            div { class: "text-muted-foreground bg-background absolute flex items-center gap-2 px-4 text-sm font-semibold",
+                                span { class: "tracking-wider", "Keys" }
+                                KeyRound { class: "" }
+                            }
+                        }
+
+                        div { class: "space-y-1 w-max text-sm items-center",
+                            for (key_index , key) in keys.iter().enumerate() {
+                                div { class: "flex items-center gap-5",
+                                    div { class: "grid items-center justify-center grid-cols-1",
+                                        span { class: "row-start-1 col-start-1 text-center text-xs font-semibold",
+                                            "{key_index + 1}"
+                                        }
+                                        Shield { class: "row-start-1 col-start-1" }
+                                    }
+
+                                    ArrowRight { class: "" }
+
+                                    div { class: "border border-border border-dashed rounded-md p-0.5",
+                                        KeyDetails {
+                                            key_with_meta: ServerWithMeta {
+                                                host: key.host.clone(),
+                                                pk: key.pk.clone(),
+                                                meta: None,
+                                            },
+                                            health: keys_health
+                                                .as_ref()
+                                                .and_then(|keys_health| keys_health.get(key_index).cloned()),
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+
+                    div { class: "",
+                        div { class: "relative mt-8 mb-5 flex items-center justify-center",
+                            Separator { class: "w-full" }
+
+                            div { class: "text-muted-foreground bg-background absolute flex items-center gap-2 px-4 text-sm font-semibold",
+                                span { class: "tracking-wider", "Sets" }
+                                Layers { class: "" }
+                            }
+                        }
+
+                        div { class: "space-y-1 w-max text-sm items-center",
+                            for (set_index , (set_keys , is_unlocked)) in sets_status.into_iter().enumerate() {
+                                div { class: "flex items-center gap-3",
+                                    match is_unlocked {
+                                        None => rsx! {
+                                            Spinner {}
+                                        },
+                                        Some(false) => rsx! {
+                                            LockKeyhole { class: "text-orange-500" }
+                                        },
+                                        Some(true) => rsx! {
+                                            LockKeyholeOpen { class: "stroke-green-500" }
+                                        },
+                                    }
+
+                                    span { class: "font-medium", "Set {set_index + 1}" }
+
+                                    ArrowRight { class: "" }
+
+                                    span { class: "text-muted-foreground",
+                                        {
+                                            set_keys
+                                                .iter()
+                                                .map(|key_index| format!("key {}", key_index + 1))
+                                                .collect::<Vec<String>>()
+                                                .join(" + ")
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
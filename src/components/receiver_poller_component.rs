@@ -0,0 +1,19 @@
+use dioxus::prelude::*;
+
+use wingedcap::client::ReceiverStored;
+
+use crate::ui::toast::{use_toast, ToastRenderer};
+
+use crate::utils::use_receiver_poller;
+
+// renders nothing, it must sit below the Toaster to toast unlocks
+#[component]
+pub fn ReceiverPoller() -> Element {
+    let mut toast = use_toast();
+
+    use_receiver_poller(Callback::new(move |receiver: ReceiverStored| {
+        toast.success(format!("\"{}\" has unlocked", receiver.label));
+    }));
+
+    rsx! {}
+}
@@ -1,9 +1,16 @@
+use std::collections::HashMap;
+
 use dioxus::prelude::*;
 
-use wingedcap::client::{Key, Receiver, ReceiverState, ReceiverStored};
+use wingedcap::{
+    client::{Key, Receiver, ReceiverState, ReceiverStored},
+    get_current_unix_time,
+};
 
 use crate::{
     components::{ReceiverProps, RoleProps, Secret},
+    storage::{get_inbox_entry, get_receiver_id, store_inbox_entry},
+    types::{InboxEntry, KeyHealth, ReceiverPoll},
     utils::get_secret,
 };
 
@@ -25,6 +32,31 @@ pub fn ReceiverSecret(
     }: ReceiverSecretProps,
 ) -> Element {
     let mut receiver_state: Signal<Option<ReceiverState>> = use_signal(|| None);
+    let mut keys_health: Signal<Option<Vec<KeyHealth>>> = use_signal(|| None);
+
+    // the background poller takes over once it has polled this receiver
+    let polls = use_context::<Signal<HashMap<String, ReceiverPoll>>>();
+
+    let poll = get_receiver_id(&receiver)
+        .ok()
+        .and_then(|id| polls.read().get(&id).cloned());
+
+    let mut inbox_entry: Signal<Option<InboxEntry>> =
+        use_signal(|| get_inbox_entry(&receiver).ok().flatten());
+
+    let receiver_to_save = receiver.clone();
+
+    let handle_save_message = move |decrypted_messages: Vec<String>| {
+        let entry = InboxEntry {
+            decrypted_messages,
+            captured_at: get_current_unix_time(),
+        };
+
+        match store_inbox_entry(&receiver_to_save, entry.clone()) {
+            Ok(()) => inbox_entry.set(Some(entry)),
+            Err(e) => tracing::error!("error saving decrypted message: {:?}", e),
+        }
+    };
 
     let receiver_with_meta = receiver.clone();
 
@@ -43,8 +75,9 @@ pub fn ReceiverSecret(
 
     use_effect(use_reactive!(|receiver| {
         spawn(async move {
-            if let Ok(state) = get_secret(receiver).await {
+            if let Ok((state, health)) = get_secret(receiver).await {
                 receiver_state.set(Some(state));
+                keys_health.set(Some(health));
             }
         });
     }));
@@ -56,7 +89,17 @@ pub fn ReceiverSecret(
             on_relabel,
             role_props: RoleProps::Receiver(ReceiverProps {
                 secret: receiver_with_meta,
-                state: receiver_state.read().clone(),
+                state: poll
+                    .as_ref()
+                    .map(|poll| poll.state.clone())
+                    .or(receiver_state.read().clone()),
+                keys_health: poll
+                    .as_ref()
+                    .map(|poll| poll.keys_health.clone())
+                    .or(keys_health()),
+                unlocked_at: poll.and_then(|poll| poll.unlocked_at),
+                inbox_entry: inbox_entry(),
+                on_save_message: Callback::new(handle_save_message),
             }),
         }
     }
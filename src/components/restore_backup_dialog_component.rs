@@ -0,0 +1,235 @@
+use dioxus::prelude::*;
+
+use lucide_dioxus::{ArchiveRestore, CircleAlert, CircleEqual, CirclePlus, TriangleAlert, X};
+
+use crate::storage::{diff_backup, read_backup, restore_backup};
+
+use crate::types::{BackupContent, BackupFile, BackupRecordStatus};
+
+use crate::ui::{
+    button::{Button, ButtonVariant},
+    card::{CardContent, CardDescription, CardHeader, CardTitle},
+    input::Input,
+    modal::{Modal, ModalBackground, ModalContent},
+};
+
+#[derive(Props, PartialEq, Clone)]
+pub struct RestoreBackupDialogProps {
+    // content of the picked backup file, the dialog is open while it is set
+    backup_json: Option<String>,
+    #[props(into)]
+    on_close: Callback<()>,
+    // number of restored records and whether the settings were restored
+    #[props(into)]
+    on_restored: Callback<(usize, bool)>,
+}
+
+#[component]
+pub fn RestoreBackupDialog(props: RestoreBackupDialogProps) -> Element {
+    let mut passphrase = use_signal(String::new);
+    let mut content: Signal<Option<BackupContent>> = use_signal(|| None);
+    let mut error: Signal<Option<String>> = use_signal(|| None);
+
+    let mut overwrite_conflicts = use_signal(|| false);
+    let mut restore_settings = use_signal(|| false);
+
+    let backup_json = props.backup_json.clone();
+
+    let is_encrypted = backup_json
+        .as_ref()
+        .and_then(|backup_json| serde_json::from_str::<BackupFile>(backup_json).ok())
+        .map(|backup| backup.salt.is_some())
+        .unwrap_or(false);
+
+    let mut handle_read = move |backup_json: &str, passphrase: Option<&str>| {
+        let read_result = read_backup(backup_json, passphrase);
+
+        match read_result {
+            Ok(backup_content) => {
+                content.set(Some(backup_content));
+                error.set(None);
+            }
+
+            Err(e) => {
+                content.set(None);
+                error.set(Some(e));
+            }
+        }
+    };
+
+    use_effect(use_reactive!(|backup_json| {
+        passphrase.set(String::new());
+        overwrite_conflicts.set(false);
+        restore_settings.set(false);
+        content.set(None);
+        error.set(None);
+
+        if let Some(backup_json) = backup_json {
+            if !is_encrypted {
+                handle_read(&backup_json, None);
+            }
+        }
+    }));
+
+    let Some(backup_json) = props.backup_json.clone() else {
+        return rsx! {};
+    };
+
+    let diff = content().map(|content| diff_backup(&content));
+
+    let handle_restore = move |_| {
+        let Some(backup_content) = content() else {
+            return;
+        };
+
+        match restore_backup(&backup_content, overwrite_conflicts(), restore_settings()) {
+            Ok(restored) => {
+                props.on_restored.call((restored, restore_settings()));
+            }
+
+            Err(e) => {
+                tracing::error!("error restoring backup: {:?}", e);
+                error.set(Some(e));
+            }
+        }
+    };
+
+    let status_icon = |status: BackupRecordStatus| match status {
+        BackupRecordStatus::New => rsx! {
+            CirclePlus { class: "size-4 shrink-0 stroke-green-500" }
+        },
+        BackupRecordStatus::Identical => rsx! {
+            CircleEqual { class: "size-4 shrink-0 text-muted-foreground" }
+        },
+        BackupRecordStatus::Conflicting => rsx! {
+            TriangleAlert { class: "size-4 shrink-0 text-orange-500" }
+        },
+    };
+
+    let status_name = |status: BackupRecordStatus| match status {
+        BackupRecordStatus::New => "new",
+        BackupRecordStatus::Identical => "identical",
+        BackupRecordStatus::Conflicting => "conflicting",
+    };
+
+    rsx! {
+        Modal {
+            open: true,
+            onopenchange: move |open: bool| {
+                if !open {
+                    props.on_close.call(());
+                }
+            },
+
+            ModalBackground {}
+
+            ModalContent { class: "md:min-w-lg",
+                div {
+                    onclick: move |_| props.on_close.call(()),
+                    class: "absolute top-2 right-2 cursor-pointer z-10",
+                    X { class: "stroke-destructive h-4 w-4 stroke-3" }
+                }
+
+                CardHeader { class: "mb-6",
+                    CardTitle { "Restore a Backup" }
+
+                    CardDescription {
+                        "Review what the backup holds compared to the stored secrets before restoring it."
+                    }
+                }
+
+                CardContent { class: "flex flex-col gap-4 w-full text-sm",
+                    if is_encrypted && content().is_none() {
+                        form {
+                            class: "flex items-center gap-2",
+                            onsubmit: move |e: FormEvent| {
+                                e.prevent_default();
+                                handle_read(&backup_json, Some(&passphrase()));
+                            },
+
+                            Input {
+                                r#type: "password",
+                                placeholder: "Backup passphrase",
+                                value: passphrase(),
+                                oninput: move |e: FormEvent| passphrase.set(e.value()),
+                            }
+
+                            Button {
+                                r#type: "submit",
+                                variant: ButtonVariant::Ghost,
+                                class: "shrink-0",
+                                disabled: passphrase.read().is_empty(),
+                                "Open"
+                            }
+                        }
+                    }
+
+                    if let Some(error) = error() {
+                        div { class: "flex items-center gap-2 text-destructive",
+                            CircleAlert { class: "size-4 shrink-0" }
+                            span { "{error}" }
+                        }
+                    }
+
+                    match diff {
+                        Some(Ok(diff)) => rsx! {
+                            div { class: "flex flex-col gap-1 max-h-[240px] overflow-y-auto",
+                                for (index , (backup_sender , status)) in diff.senders.into_iter().enumerate() {
+                                    div { key: "sender-{index}", class: "flex items-center gap-2",
+                                        {status_icon(status)}
+                                        span { class: "grow truncate", "{backup_sender.sender.label}" }
+                                        span { class: "text-xs text-muted-foreground", "sender, {status_name(status)}" }
+                                    }
+                                }
+
+                                for (index , (backup_receiver , status)) in diff.receivers.into_iter().enumerate() {
+                                    div { key: "receiver-{index}", class: "flex items-center gap-2",
+                                        {status_icon(status)}
+                                        span { class: "grow truncate", "{backup_receiver.receiver.label}" }
+                                        span { class: "text-xs text-muted-foreground", "receiver, {status_name(status)}" }
+                                    }
+                                }
+                            }
+
+                            label { class: "flex items-center gap-2 cursor-pointer",
+                                input {
+                                    r#type: "checkbox",
+                                    class: "size-4 accent-primary",
+                                    checked: overwrite_conflicts(),
+                                    onchange: move |e: FormEvent| overwrite_conflicts.set(e.checked()),
+                                }
+                                span { "Replace conflicting secrets, otherwise stored ones are kept" }
+                            }
+
+                            label { class: "flex items-center gap-2 cursor-pointer",
+                                input {
+                                    r#type: "checkbox",
+                                    class: "size-4 accent-primary",
+                                    checked: restore_settings(),
+                                    onchange: move |e: FormEvent| restore_settings.set(e.checked()),
+                                }
+                                span { "Restore settings too" }
+                            }
+
+                            Button {
+                                class: "mt-2 w-full gap-4",
+                                onclick: handle_restore,
+                                "Restore"
+                                ArchiveRestore { class: "" }
+                            }
+                        },
+
+                        Some(Err(e)) => rsx! {
+                            div { class: "flex items-center gap-2 text-destructive",
+                                CircleAlert { class: "size-4 shrink-0" }
+                                span { "{e}" }
+                            }
+                        },
+
+                        None => rsx! {},
+                    }
+                }
+            }
+        }
+    }
+}
@@ -1,22 +1,175 @@
 use dioxus::prelude::*;
 
-use lucide_dioxus::Eye;
+use base64::{engine::general_purpose::STANDARD, Engine};
+
+use lucide_dioxus::{
+    CheckCheck, Copy, CopyCheck, Eye, EyeOff, File, FileDown, HardDriveDownload, LockKeyholeOpen,
+    TriangleAlert,
+};
 
 use crate::ui::{
+    button::{Button, ButtonVariant},
     card::{CardDescription, CardHeader, CardTitle},
+    input::Input,
     modal::{Modal, ModalBackground, ModalContent, ModalTrigger},
+    toast::{use_toast, ToastRenderer},
+};
+
+use crate::storage::is_vault_enabled;
+
+use crate::types::{InboxEntry, SecretPayload};
+
+use crate::utils::{
+    copy_secret_to_clipboard, decrypt_message_with_passphrase, detect_secret_payload,
+    format_byte_size, group_decrypted_messages, save_file,
+    time::{format_unix_time, wait_util::wait},
 };
 
 #[derive(PartialEq, Props, Clone)]
 pub struct RevealSecretDialogProps {
-    pub decrypted_messages: Vec<String>,
+    // None when the key servers could not be asked, the saved copy is shown instead
+    pub decrypted_messages: Option<Vec<String>>,
+    pub inbox_entry: Option<InboxEntry>,
+    pub on_save: EventHandler<Vec<String>>,
 }
 
 #[component]
 pub fn RevealSecretDialog(
-    RevealSecretDialogProps { decrypted_messages }: RevealSecretDialogProps,
+    RevealSecretDialogProps {
+        decrypted_messages,
+        inbox_entry,
+        on_save,
+    }: RevealSecretDialogProps,
 ) -> Element {
-    let decrypted_message = decrypted_messages.first();
+    let mut toast = use_toast();
+
+    let mut is_visible = use_signal(|| true);
+    let mut is_just_copied = use_signal(|| false);
+    let mut selected_group = use_signal(|| 0);
+
+    let mut passphrase = use_signal(String::new);
+    // the selected message once opened with the recipient passphrase, if the sender set one
+    let mut opened_message: Signal<Option<String>> = use_signal(|| None);
+
+    let is_saved = match (&decrypted_messages, &inbox_entry) {
+        (Some(decrypted_messages), Some(inbox_entry)) => {
+            *decrypted_messages == inbox_entry.decrypted_messages
+        }
+        (None, Some(_)) => true,
+        _ => false,
+    };
+
+    let is_offline = decrypted_messages.is_none();
+
+    let messages = decrypted_messages
+        .clone()
+        .or(inbox_entry
+            .as_ref()
+            .map(|entry| entry.decrypted_messages.clone()))
+        .unwrap_or_default();
+
+    // every unlocked set should hold the same message, more than one group means they disagree
+    let groups = group_decrypted_messages(&messages);
+
+    let decrypted_message = groups
+        .get(selected_group().min(groups.len().saturating_sub(1)))
+        .map(|(message, _)| message.clone());
+
+    let message = match opened_message() {
+        Some(opened_message) => Some(opened_message),
+        None => decrypted_message.clone(),
+    };
+
+    let payload = message.as_deref().map(detect_secret_payload);
+
+    let is_protected = matches!(payload, Some(SecretPayload::PassphraseProtected(_)));
+
+    let envelope = decrypted_message.clone();
+
+    let handle_open = move |e: FormEvent| {
+        e.prevent_default();
+
+        let Some(envelope) = envelope.clone() else {
+            return;
+        };
+
+        match decrypt_message_with_passphrase(&envelope, &passphrase()) {
+            Ok(message) => {
+                opened_message.set(Some(message));
+                passphrase.set(String::new());
+            }
+
+            Err(e) => {
+                tracing::error!("error opening message: {:?}", e);
+                toast.error(format!("Failed to open the message: {}", e));
+            }
+        }
+    };
+
+    let save_description = if is_vault_enabled() {
+        "Key servers may forget released keys, keep a copy encrypted with your vault on this device."
+    } else {
+        "Key servers may forget released keys, keep a copy on this device."
+    };
+
+    let message_to_copy = message.clone();
+
+    let handle_copy = move |_| {
+        let Some(message) = message_to_copy.clone() else {
+            return;
+        };
+
+        match copy_secret_to_clipboard(&message) {
+            Ok(()) => {
+                is_just_copied.set(true);
+
+                spawn(async move {
+                    wait(2000).await;
+                    is_just_copied.set(false);
+                });
+            }
+
+            Err(e) => {
+                tracing::error!("error copying secret: {:?}", e);
+                toast.error(format!("Failed to copy the secret: {}", e));
+            }
+        }
+    };
+
+    let file_date = chrono::Local::now().format("%Y-%m-%d");
+
+    // attachments keep their own name, messages are saved as text or json files
+    let file_to_save = match (&payload, &message) {
+        (Some(SecretPayload::File(attachment)), _) => Some((
+            attachment.name.clone(),
+            attachment.bytes.clone(),
+            attachment.mime.clone(),
+        )),
+        (Some(SecretPayload::Json(_)), Some(message)) => Some((
+            format!("wingedcap-secret-{}.json", file_date),
+            message.clone().into_bytes(),
+            "application/json".to_string(),
+        )),
+        (_, Some(message)) => Some((
+            format!("wingedcap-secret-{}.txt", file_date),
+            message.clone().into_bytes(),
+            "text/plain".to_string(),
+        )),
+        _ => None,
+    };
+
+    let handle_save_to_file = Callback::new(move |_: MouseEvent| {
+        let Some((file_name, bytes, mime_type)) = file_to_save.clone() else {
+            return;
+        };
+
+        spawn(async move {
+            if let Err(e) = save_file(&file_name, &bytes, &mime_type).await {
+                tracing::error!("error saving secret: {:?}", e);
+                toast.error(format!("Failed to save the secret: {}", e));
+            }
+        });
+    });
 
     rsx! {
         Modal {
@@ -27,14 +180,177 @@ pub fn RevealSecretDialog(
             ModalBackground {}
 
             ModalContent { class: "md:min-w-lg",
-                if let Some(decrypted_message) = decrypted_message {
+                if let Some(payload) = payload {
                     CardHeader { class: "mb-4",
                         CardTitle { "Secret unlocked" }
-                        CardDescription { "You can finally read it !" }
+
+                        if is_offline {
+                            CardDescription { "Showing the copy saved on this device." }
+                        } else {
+                            CardDescription { "You can finally read it !" }
+                        }
+                    }
+
+                    if groups.len() > 1 {
+                        div { class: "flex items-center gap-4 mb-4 rounded-md border border-destructive bg-destructive/10 px-4 py-2 text-sm text-destructive",
+                            TriangleAlert { class: "h-4 w-4 shrink-0" }
+                            span {
+                                "The unlocked sets disagree, they hold {groups.len()} different messages. Ask the sender which one is right."
+                            }
+                        }
+
+                        div { class: "flex flex-wrap items-center gap-2 mb-4",
+                            for (group_index , (_ , set_indexes)) in groups.iter().enumerate() {
+                                Button {
+                                    key: "{group_index}",
+                                    variant: if group_index == selected_group() { ButtonVariant::Default } else { ButtonVariant::Outline },
+                                    onclick: move |_| {
+                                        selected_group.set(group_index);
+                                        opened_message.set(None);
+                                    },
+                                    {
+                                        let set_numbers = set_indexes
+                                            .iter()
+                                            .map(|set_index| (set_index + 1).to_string())
+                                            .collect::<Vec<String>>()
+                                            .join(", ");
+
+                                        if set_indexes.len() > 1 {
+                                            format!("Sets {}", set_numbers)
+                                        } else {
+                                            format!("Set {}", set_numbers)
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    } else if messages.len() > 1 {
+                        div { class: "flex items-center gap-2 mb-4 text-sm text-muted-foreground",
+                            CheckCheck { class: "h-4 w-4 shrink-0 stroke-green-500" }
+                            span { "All {messages.len()} unlocked sets hold the same message" }
+                        }
+                    }
+
+                    div { class: "flex items-center justify-end gap-1 mb-2",
+                        hidden: is_protected,
+
+                        Button {
+                            variant: ButtonVariant::Ghost,
+                            class: "px-0",
+                            onclick: move |_| is_visible.toggle(),
+                            if is_visible() {
+                                EyeOff { class: "" }
+                            } else {
+                                Eye { class: "" }
+                            }
+                        }
+
+                        if !matches!(payload, SecretPayload::File(_)) {
+                            Button {
+                                variant: ButtonVariant::Ghost,
+                                class: "px-0",
+                                onclick: handle_copy,
+                                if is_just_copied() {
+                                    CopyCheck { class: "" }
+                                } else {
+                                    Copy { class: "" }
+                                }
+                            }
+                        }
+
+                        Button {
+                            variant: ButtonVariant::Ghost,
+                            class: "px-0",
+                            onclick: handle_save_to_file,
+                            FileDown { class: "" }
+                        }
                     }
 
                     div { class: "max-h-[50vh] overflow-auto rounded-lg border border-border/60 bg-muted/30 p-4 text-sm leading-relaxed",
-                        "{decrypted_message}"
+                        if !is_visible() {
+                            span { class: "text-muted-foreground select-none", "Hidden, click the eye to show it again" }
+                        } else {
+                            match payload {
+                                SecretPayload::Json(value) => rsx! {
+                                    pre { class: "font-mono text-xs whitespace-pre-wrap break-all",
+                                        {serde_json::to_string_pretty(&value).unwrap_or_default()}
+                                    }
+                                },
+                                SecretPayload::File(attachment) => rsx! {
+                                    div { class: "flex flex-col gap-3",
+                                        div { class: "flex items-center gap-3",
+                                            File { class: "size-8 shrink-0 stroke-primary" }
+
+                                            div { class: "grid min-w-0 grow",
+                                                span { class: "truncate font-medium", "{attachment.name}" }
+                                                span { class: "text-xs text-muted-foreground",
+                                                    "{attachment.mime}, {format_byte_size(attachment.bytes.len())}"
+                                                }
+                                            }
+
+                                            Button {
+                                                variant: ButtonVariant::Outline,
+                                                class: "shrink-0",
+                                                onclick: handle_save_to_file,
+                                                FileDown { class: "size-4" }
+                                                "Save file"
+                                            }
+                                        }
+
+                                        if attachment.mime.starts_with("image/") && attachment.mime != "image/svg+xml" {
+                                            img {
+                                                class: "max-h-[40vh] rounded-md object-contain",
+                                                src: "data:{attachment.mime};base64,{STANDARD.encode(&attachment.bytes)}",
+                                            }
+                                        }
+                                    }
+                                },
+                                SecretPayload::PassphraseProtected(_) => rsx! {
+                                    form { class: "flex flex-col gap-3", onsubmit: handle_open,
+                                        span { class: "text-muted-foreground",
+                                            "The sender protected this message with a passphrase, they should have given it to you separately."
+                                        }
+
+                                        div { class: "flex items-center gap-2",
+                                            Input {
+                                                r#type: "password",
+                                                placeholder: "Passphrase",
+                                                value: passphrase(),
+                                                oninput: move |e: FormEvent| passphrase.set(e.value()),
+                                            }
+
+                                            Button {
+                                                r#type: "submit",
+                                                variant: ButtonVariant::Outline,
+                                                class: "shrink-0",
+                                                disabled: passphrase().is_empty(),
+                                                LockKeyholeOpen { class: "size-4" }
+                                                "Open"
+                                            }
+                                        }
+                                    }
+                                },
+                                SecretPayload::Text(text) => rsx! {
+                                    p { class: "whitespace-pre-wrap break-words", "{text}" }
+                                },
+                            }
+                        }
+                    }
+
+                    div { class: "mt-4 flex items-center justify-between gap-4 text-xs text-muted-foreground",
+                        if let (true, Some(inbox_entry)) = (is_saved, inbox_entry.as_ref()) {
+                            span { "Saved on this device, captured {format_unix_time(inbox_entry.captured_at)}" }
+                        } else {
+                            span { "{save_description}" }
+
+                            Button {
+                                variant: ButtonVariant::Outline,
+                                class: "shrink-0",
+                                onclick: move |_| on_save.call(messages.clone()),
+                                HardDriveDownload { class: "size-4" }
+                                "Save a copy"
+                            }
+                        }
                     }
                 } else {
                     CardHeader { class: "mb-4",
@@ -8,20 +8,37 @@ use crate::ui::button::{Button, ButtonVariant};
 
 use crate::ui::spinner::Spinner;
 
-use crate::components::{RevealSecretDialog, SenderDetailsDialog};
+use crate::components::{
+    KeyHealthIndicator, ReceiverDetailsDialog, RevealSecretDialog, SenderDetailsDialog,
+};
 
-use crate::utils::time::wait_util::wait;
+use crate::types::{InboxEntry, KeyHealth, SenderMeta, ShareStatus};
+
+use crate::utils::time::{
+    format_countdown, format_time_ago, format_unix_time, get_key_unlock_times,
+    get_projected_unlock, use_now, wait_util::wait,
+};
 
 #[derive(PartialEq, Props, Clone)]
 pub struct SenderProps {
     pub secret: SenderStored,
     pub state: Option<SenderState>,
+    pub keys_health: Option<Vec<KeyHealth>>,
+    pub next_ping: Option<i64>,
+    pub meta: Option<SenderMeta>,
+    pub share_status: Option<ShareStatus>,
+    pub on_share_receiver: EventHandler<()>,
+    pub on_confirm_receiver: EventHandler<()>,
 }
 
 #[derive(PartialEq, Props, Clone)]
 pub struct ReceiverProps {
     pub secret: ReceiverStored,
     pub state: Option<ReceiverState>,
+    pub keys_health: Option<Vec<KeyHealth>>,
+    pub unlocked_at: Option<i64>,
+    pub inbox_entry: Option<InboxEntry>,
+    pub on_save_message: EventHandler<Vec<String>>,
 }
 
 #[derive(PartialEq, Clone)]
@@ -49,6 +66,8 @@ pub fn Secret(
 ) -> Element {
     let mut is_just_copied = use_signal(|| false);
 
+    let now = use_now(1000);
+
     let handle_copy = move |_| {
         on_copy.call(());
         is_just_copied.set(true);
@@ -64,6 +83,44 @@ pub fn Secret(
         RoleProps::Receiver(ReceiverProps { secret, .. }) => secret.label.clone(),
     };
 
+    let keys_health = match role_props.clone() {
+        RoleProps::Sender(SenderProps { keys_health, .. }) => keys_health,
+        RoleProps::Receiver(ReceiverProps { keys_health, .. }) => keys_health,
+    };
+
+    let next_ping = match role_props.clone() {
+        RoleProps::Sender(SenderProps {
+            next_ping: Some(next_ping),
+            ..
+        }) => Some(format_unix_time(next_ping)),
+        _ => None,
+    };
+
+    let last_ping = match role_props.clone() {
+        RoleProps::Sender(SenderProps {
+            meta: Some(meta), ..
+        }) => meta
+            .last_ping()
+            .map(|last_ping| format_time_ago(last_ping, &now())),
+        _ => None,
+    };
+
+    // not worth projecting once the secret is unlocked
+    let unlock_countdown = match role_props.clone() {
+        RoleProps::Sender(SenderProps {
+            secret,
+            meta: Some(meta),
+            state,
+            ..
+        }) if !matches!(state, Some(SenderState::Unlocked { .. })) => {
+            let key_unlock_times = get_key_unlock_times(&meta, secret.keys.len());
+
+            get_projected_unlock(&key_unlock_times, &secret.sets)
+                .map(|unlock_at| format_countdown(unlock_at, &now()))
+        }
+        _ => None,
+    };
+
     let status_icon = match role_props {
         RoleProps::Sender(SenderProps { state: None, .. })
         | RoleProps::Receiver(ReceiverProps { state: None, .. }) => rsx! {
@@ -101,31 +158,87 @@ pub fn Secret(
                 }
 
                 h1 { class: "flex grow items-center gap-2 text-sm font-semibold min-w-0",
-                    div { class: "grid place-content-center min-w-0",
+                    div { class: "grid min-w-0",
                         span { class: "truncate", "{label}" }
+
+                        div { class: "flex items-center gap-2 min-w-0 text-xs font-normal text-muted-foreground",
+                            if let Some(keys_health) = keys_health.clone() {
+                                div { class: "flex items-center gap-1 shrink-0",
+                                    for (index , health) in keys_health.into_iter().enumerate() {
+                                        KeyHealthIndicator { key: "{index}", health, class: "size-3" }
+                                    }
+                                }
+                            }
+
+                            match unlock_countdown {
+                                Some(Some(countdown)) => rsx! {
+                                    span { class: "truncate", "unlocks in {countdown} if you stop pinging" }
+                                },
+                                Some(None) => rsx! {
+                                    span { class: "truncate text-orange-500", "may unlock any moment" }
+                                },
+                                None => rsx! {},
+                            }
+
+                            if let Some(last_ping) = last_ping {
+                                span { class: "truncate", "last ping {last_ping}" }
+                            }
+
+                            if let Some(next_ping) = next_ping {
+                                span { class: "truncate", "next ping at {next_ping}" }
+                            }
+                        }
                     }
                 }
             }
 
             div { class: "flex items-center gap-1 shrink-0",
-                if let RoleProps::Receiver(
-                    ReceiverProps { state: Some(ReceiverState::Unlocked { unlocked_sets, .. }), .. },
-                ) = role_props.clone()
-                {
+                if let RoleProps::Receiver(ReceiverProps { state, inbox_entry, on_save_message, .. }) = role_props.clone() {
                     {
-                        let decrypted_messages: Vec<String> = unlocked_sets
-                            .iter()
-                            .map(|set| set.decrypted_data.clone())
-                            .collect();
+                        let decrypted_messages = match state {
+                            Some(ReceiverState::Unlocked { unlocked_sets, .. }) => Some(
+                                unlocked_sets
+                                    .iter()
+                                    .map(|set| set.decrypted_data.clone())
+                                    .collect::<Vec<String>>(),
+                            ),
+                            _ => None,
+                        };
 
-                        rsx! {
-                            RevealSecretDialog { decrypted_messages }
+                        // the saved copy keeps the secret readable once the key servers forget the keys
+                        if decrypted_messages.is_some() || inbox_entry.is_some() {
+                            rsx! {
+                                RevealSecretDialog { decrypted_messages, inbox_entry, on_save: on_save_message }
+                            }
+                        } else {
+                            rsx! {}
                         }
                     }
                 }
 
-                if let RoleProps::Sender(SenderProps { secret, .. }) = role_props.clone() {
-                    SenderDetailsDialog { secret, on_relabel }
+                if let RoleProps::Sender(
+                    SenderProps { secret, meta, share_status, on_share_receiver, on_confirm_receiver, .. },
+                ) = role_props.clone()
+                {
+                    SenderDetailsDialog {
+                        secret,
+                        keys_health: keys_health.clone(),
+                        meta,
+                        on_relabel,
+                        share_status,
+                        on_share_receiver,
+                        on_confirm_receiver,
+                    }
+                }
+
+                if let RoleProps::Receiver(ReceiverProps { secret, state, unlocked_at, .. }) = role_props.clone() {
+                    ReceiverDetailsDialog {
+                        secret,
+                        state,
+                        keys_health,
+                        unlocked_at,
+                        on_relabel,
+                    }
                 }
 
                 Button {
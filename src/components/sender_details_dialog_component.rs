@@ -1,6 +1,9 @@
 use dioxus::prelude::*;
 
-use lucide_dioxus::{ArrowRight, Info, KeyRound, Pencil, Save, Shield, TableOfContents, X};
+use lucide_dioxus::{
+    ArrowRight, CheckCheck, History, Info, KeyRound, Pencil, Save, Share2, Shield, TableOfContents,
+    X,
+};
 use wingedcap::client::{get_vault_conf, SenderStored, ServerWithMeta, VaultConf};
 
 use crate::ui::button::{Button, ButtonVariant};
@@ -12,27 +15,50 @@ use crate::ui::card::{CardContent, CardDescription, CardHeader, CardTitle};
 
 use crate::components::KeyDetails;
 
+use crate::types::{KeyHealth, SenderMeta, ShareStatus};
+
+use crate::utils::time::{
+    format_countdown, format_time_ago, format_unix_time, get_key_unlock_times,
+    get_projected_unlock, get_time_unit_name, time_to_seconds, use_now,
+};
+
 #[derive(PartialEq, Props, Clone)]
 pub struct SenderDetailsDialogProps {
     pub secret: SenderStored,
+    pub keys_health: Option<Vec<KeyHealth>>,
+    pub meta: Option<SenderMeta>,
     pub on_relabel: EventHandler<String>,
+    // None when no receiver data is stored for this sender
+    pub share_status: Option<ShareStatus>,
+    pub on_share_receiver: EventHandler<()>,
+    pub on_confirm_receiver: EventHandler<()>,
 }
 
 #[component]
 pub fn SenderDetailsDialog(
-    SenderDetailsDialogProps { secret, on_relabel }: SenderDetailsDialogProps,
+    SenderDetailsDialogProps {
+        secret,
+        keys_health,
+        meta,
+        on_relabel,
+        share_status,
+        on_share_receiver,
+        on_confirm_receiver,
+    }: SenderDetailsDialogProps,
 ) -> Element {
     let mut edited_label: Signal<Option<String>> = use_signal(|| None);
 
+    let now = use_now(1000);
+
     let label = secret.label;
 
     let keys = secret.keys;
 
-    let sets = secret.sets.iter().map(|keys| keys.clone()).collect();
+    let sets: Vec<_> = secret.sets.to_vec();
 
     let num_keys = keys.len();
 
-    let vault_conf = get_vault_conf(sets);
+    let vault_conf = get_vault_conf(sets.clone());
 
     let vault_conf_description = match vault_conf {
         VaultConf::Standard { total, required } => {
@@ -41,6 +67,75 @@ pub fn SenderDetailsDialog(
         VaultConf::Custom => "Custom".to_string(),
     };
 
+    let meta = meta.unwrap_or_default();
+
+    let created_at_description = meta
+        .created_at
+        .map(format_unix_time)
+        .unwrap_or("Unknown".to_string());
+
+    let timelock_description = meta
+        .timelock
+        .clone()
+        .map(|timelock| {
+            format!(
+                "{} {}",
+                timelock.magnitude,
+                get_time_unit_name(timelock.unit)
+            )
+        })
+        .unwrap_or("Unknown".to_string());
+
+    let required_keys_description = meta
+        .required_keys
+        .map(|required_keys| required_keys.to_string())
+        .unwrap_or("Unknown".to_string());
+
+    let last_ping_description = meta
+        .last_ping()
+        .map(|last_ping| format_time_ago(last_ping, &now()))
+        .unwrap_or("Never".to_string());
+
+    let timelock_seconds = meta.timelock.clone().map(time_to_seconds);
+
+    let key_unlock_times = get_key_unlock_times(&meta, num_keys);
+
+    let projected_unlock_description = match get_projected_unlock(&key_unlock_times, &sets) {
+        Some(unlock_at) => match format_countdown(unlock_at, &now()) {
+            Some(countdown) => format!(
+                "in {} ({}) without pings",
+                countdown,
+                format_unix_time(unlock_at.timestamp())
+            ),
+            None => "Due, may unlock any moment".to_string(),
+        },
+        None => "Unknown".to_string(),
+    };
+
+    // remaining share of the timelock of each key, for the timeline bars
+    let key_timelines: Vec<Option<(f64, Option<String>)>> = key_unlock_times
+        .iter()
+        .map(|unlock_at| {
+            let unlock_at = (*unlock_at)?;
+            let timelock_seconds = timelock_seconds.filter(|seconds| *seconds > 0)?;
+
+            let remaining = (unlock_at - now()).num_seconds().max(0) as f64;
+
+            Some((
+                (remaining / timelock_seconds as f64).min(1.0),
+                format_countdown(unlock_at, &now()),
+            ))
+        })
+        .collect();
+
+    let ping_history: Vec<_> = meta.ping_history.iter().rev().cloned().collect();
+
+    let share_status_description = share_status.map(|share_status| match share_status {
+        ShareStatus::NotShared => "Not shared yet",
+        ShareStatus::Copied => "Copied, not confirmed by the recipient",
+        ShareStatus::Confirmed => "Received by the recipient",
+    });
+
     let label_for_render = label.clone();
 
     let label_for_handle_enable_editing = label.clone();
@@ -156,6 +251,58 @@ pub fn SenderDetailsDialog(
 
                             span { class: "", "Vault type" }
                             span { class: "", "{vault_conf_description}" }
+
+                            span { class: "", "Required keys" }
+                            span { class: "", "{required_keys_description}" }
+
+                            span { class: "", "Timelock" }
+                            span { class: "", "{timelock_description}" }
+
+                            span { class: "", "Created" }
+                            span { class: "", "{created_at_description}" }
+
+                            span { class: "", "Last ping" }
+                            span { class: "", "{last_ping_description}" }
+
+                            span { class: "", "Projected unlock" }
+                            span { class: "", "{projected_unlock_description}" }
+                        }
+                    }
+
+                    if let Some(share_status_description) = share_status_description {
+                        div { class: "",
+                            div { class: "relative mt-8 mb-5 flex items-center justify-center",
+                                Separator { class: "w-full" }
+
+                                div { class: "text-muted-foreground bg-background absolute flex items-center gap-2 px-4 text-sm font-semibold",
+                                    span { class: "tracking-wider", "Receiver data" }
+                                    Share2 { class: "" }
+                                }
+                            }
+
+                            div { class: "flex items-center justify-between gap-4 text-sm",
+                                span { class: "", "{share_status_description}" }
+
+                                div { class: "flex items-center gap-2 shrink-0",
+                                    Button {
+                                        variant: ButtonVariant::Outline,
+                                        class: "h-8 gap-2",
+                                        onclick: move |_| on_share_receiver.call(()),
+                                        "Share again"
+                                        Share2 { class: "size-4" }
+                                    }
+
+                                    if share_status != Some(ShareStatus::Confirmed) {
+                                        Button {
+                                            variant: ButtonVariant::Outline,
+                                            class: "h-8 gap-2",
+                                            onclick: move |_| on_confirm_receiver.call(()),
+                                            "Mark as received"
+                                            CheckCheck { class: "size-4" }
+                                        }
+                                    }
+                                }
+                            }
                         }
                     }
 
@@ -188,12 +335,58 @@ pub fn SenderDetailsDialog(
                                                 pk: key.pk.clone(),
                                                 meta: key.meta.clone(),
                                             },
+                                            health: keys_health
+                                                .as_ref()
+                                                .and_then(|keys_health| keys_health.get(key_index).cloned()),
+                                        }
+                                    }
+
+                                    div { class: "flex flex-col gap-1 text-xs text-muted-foreground",
+                                        if let Some(Some(last_ping)) = meta.last_pings.get(key_index) {
+                                            span { "last ping {format_time_ago(*last_ping, &now())}" }
+                                        }
+
+                                        if let Some(Some((remaining_share, countdown))) = key_timelines.get(key_index).cloned() {
+                                            div { class: "h-1.5 w-24 overflow-hidden rounded-full bg-muted",
+                                                div {
+                                                    class: "h-full rounded-full bg-primary transition-all",
+                                                    style: "width: {remaining_share * 100.0}%",
+                                                }
+                                            }
+
+                                            if let Some(countdown) = countdown {
+                                                span { "unlocks in {countdown}" }
+                                            } else {
+                                                span { class: "text-orange-500", "unlock due" }
+                                            }
                                         }
                                     }
                                 }
                             }
                         }
                     }
+
+                    if !ping_history.is_empty() {
+                        div { class: "",
+                            div { class: "relative mt-8 mb-5 flex items-center justify-center",
+                                Separator { class: "w-full" }
+
+                                div { class: "text-muted-foreground bg-background absolute flex items-center gap-2 px-4 text-sm font-semibold",
+                                    span { class: "tracking-wider", "Ping history" }
+                                    History { class: "" }
+                                }
+                            }
+
+                            div { class: "grid grid-cols-[auto_1fr] gap-x-4 gap-y-1 w-max max-h-[160px] overflow-y-auto pr-2 text-sm",
+                                for (index , ping) in ping_history.iter().enumerate() {
+                                    span { key: "at-{index}", class: "font-medium", "{format_unix_time(ping.at)}" }
+                                    span { key: "keys-{index}", class: "text-muted-foreground",
+                                        "{ping.reachable_keys} of {ping.total_keys} keys reachable"
+                                    }
+                                }
+                            }
+                        }
+                    }
                 }
             }
         }
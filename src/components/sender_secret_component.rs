@@ -1,10 +1,13 @@
+use std::collections::HashMap;
+
 use dioxus::prelude::*;
 
-use wingedcap::client::{Key, Sender, SenderState, SenderStored};
+use wingedcap::client::SenderStored;
 
 use crate::{
     components::{RoleProps, Secret, SenderProps},
-    utils::ping_secret,
+    storage::get_sender_id,
+    types::{Heartbeat, ShareStatus},
 };
 
 #[derive(PartialEq, Props, Clone)]
@@ -13,6 +16,9 @@ pub struct SenderSecretProps {
     pub on_relabel: EventHandler<String>,
     pub on_copy: EventHandler<()>,
     pub on_remove: EventHandler<()>,
+    pub share_status: Option<ShareStatus>,
+    pub on_share_receiver: EventHandler<()>,
+    pub on_confirm_receiver: EventHandler<()>,
 }
 
 #[component]
@@ -22,32 +28,17 @@ pub fn SenderSecret(
         on_relabel,
         on_copy,
         on_remove,
+        share_status,
+        on_share_receiver,
+        on_confirm_receiver,
     }: SenderSecretProps,
 ) -> Element {
-    let mut sender_state: Signal<Option<SenderState>> = use_signal(|| None);
-
-    let sender_with_meta = sender.clone();
-
-    let SenderStored { keys, sets, .. } = sender.clone();
-
-    let keys: Vec<Key> = keys
-        .iter()
-        .map(|key| Key {
-            host: key.host.clone(),
-            pk: key.pk.clone(),
-            id: key.id.clone(),
-        })
-        .collect();
-
-    let sender = Sender { keys, sets };
+    // kept up to date by the HeartbeatScheduler
+    let heartbeats = use_context::<Signal<HashMap<String, Heartbeat>>>();
 
-    use_effect(use_reactive!(|sender| {
-        spawn(async move {
-            if let Ok(state) = ping_secret(sender).await {
-                sender_state.set(Some(state));
-            }
-        });
-    }));
+    let heartbeat = get_sender_id(&sender)
+        .ok()
+        .and_then(|id| heartbeats.read().get(&id).cloned());
 
     rsx! {
         Secret {
@@ -55,8 +46,14 @@ pub fn SenderSecret(
             on_copy,
             on_remove,
             role_props: RoleProps::Sender(SenderProps {
-                secret: sender_with_meta,
-                state: sender_state.read().clone(),
+                secret: sender,
+                state: heartbeat.as_ref().and_then(|heartbeat| heartbeat.state.clone()),
+                keys_health: heartbeat.as_ref().and_then(|heartbeat| heartbeat.keys_health.clone()),
+                next_ping: heartbeat.as_ref().and_then(|heartbeat| heartbeat.next_ping),
+                meta: heartbeat.and_then(|heartbeat| heartbeat.meta),
+                share_status,
+                on_share_receiver,
+                on_confirm_receiver,
             }),
         }
     }
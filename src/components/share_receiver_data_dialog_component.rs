@@ -1,12 +1,26 @@
 use dioxus::prelude::*;
-use lucide_dioxus::{ClipboardCheck, Copy, TriangleAlert, X};
+use lucide_dioxus::{
+    CheckCheck, ClipboardCheck, Copy, Info, LockKeyhole, QrCode, TriangleAlert, Users, X,
+};
+
+use wingedcap::client::{Receiver, ReceiverStored};
+
+use crate::components::QrCodeParts;
+
+use crate::types::SetKeyOutcome;
 
 use crate::ui::{
     button::{Button, ButtonVariant},
     card::{CardContent, CardDescription, CardHeader, CardTitle},
+    hovercard::{HoverCard, HoverCardContent, HoverCardTrigger},
+    input::Input,
     modal::{Modal, ModalBackground, ModalContent},
 };
 
+use crate::constants::MAX_SPLIT_SHARES;
+
+use crate::utils::{encrypt_receiver_share, split_receiver_data};
+
 #[derive(Clone, Copy, Debug)]
 pub struct ShareReceiverDataDialogState {
     _open: bool,
@@ -40,8 +54,21 @@ impl ShareReceiverDataDialogState {
 
 #[derive(Props, PartialEq, Clone)]
 pub struct ShareReceiverDataDialogProps {
+    // receives the shared data, encrypted when a passphrase was set
     #[props(into)]
-    on_copy: Callback<()>,
+    on_copy: Callback<String>,
+    #[props(optional)]
+    on_show_qr_code: Option<Callback<()>>,
+    receiver: Option<Receiver>,
+    // shared along with the receiver data when given, as in a backup
+    #[props(optional)]
+    label: Option<String>,
+    // servers that failed when the secret was created, it holds no key from them
+    #[props(default)]
+    skipped_keys: Vec<SetKeyOutcome>,
+    // shared again from a stored sender rather than right after its creation
+    #[props(default)]
+    is_reshare: bool,
     #[props(optional)]
     open: Option<bool>,
     #[props(optional)]
@@ -60,21 +87,134 @@ pub fn ShareReceiverDataDialog(props: ShareReceiverDataDialogProps) -> Element {
     }));
 
     let mut is_copied = use_signal(|| false);
+    let mut is_qr_code_shown = use_signal(|| false);
+
+    let mut passphrase: Signal<Option<String>> = use_signal(|| None);
+    let mut confirm_passphrase = use_signal(String::new);
+
+    // built once, copying and scanning must give the same encrypted data
+    let mut share_payload: Signal<Option<String>> = use_signal(|| None);
+
+    // threshold and total shares when the data is split between several people
+    let mut split: Signal<Option<(u8, u8)>> = use_signal(|| None);
+    let mut split_shares: Signal<Option<Vec<String>>> = use_signal(|| None);
+    // shares copied or shown so far
+    let mut handed_out_shares: Signal<Vec<usize>> = use_signal(Vec::new);
+    let mut shown_share: Signal<Option<usize>> = use_signal(|| None);
 
     use_effect(move || {
         if !state().is_open() {
             is_copied.set(false);
+            is_qr_code_shown.set(false);
+            passphrase.set(None);
+            confirm_passphrase.set(String::new());
+            share_payload.set(None);
+            split.set(None);
+            split_shares.set(None);
+            handed_out_shares.set(vec![]);
+            shown_share.set(None);
         }
     });
 
+    let receiver_data = props
+        .receiver
+        .as_ref()
+        .and_then(|receiver| match props.label.clone() {
+            Some(label) => serde_json::to_string(&ReceiverStored {
+                label,
+                keys: receiver.keys.clone(),
+                sets: receiver.sets.clone(),
+            })
+            .ok(),
+            None => serde_json::to_string(receiver).ok(),
+        });
+
+    let is_passphrase_invalid = match passphrase() {
+        Some(passphrase) => passphrase.is_empty() || passphrase != confirm_passphrase(),
+        None => false,
+    };
+
+    let prepare_payload = Callback::new(move |_: ()| -> Option<String> {
+        if let Some(payload) = share_payload() {
+            return Some(payload);
+        }
+
+        let receiver_data = receiver_data.clone()?;
+
+        let payload = match passphrase() {
+            Some(passphrase) => match encrypt_receiver_share(&receiver_data, &passphrase) {
+                Ok(payload) => payload,
+
+                Err(e) => {
+                    tracing::error!("error encrypting receiver data: {:?}", e);
+                    return None;
+                }
+            },
+            None => receiver_data,
+        };
+
+        share_payload.set(Some(payload.clone()));
+
+        Some(payload)
+    });
+
     let handle_copy = move |_| {
+        let Some(payload) = prepare_payload.call(()) else {
+            return;
+        };
+
         spawn(async move {
-            props.on_copy.call(());
+            props.on_copy.call(payload);
         });
 
         is_copied.set(true);
     };
 
+    let handle_show_qr_code = move |_| {
+        if prepare_payload.call(()).is_none() {
+            return;
+        }
+
+        if let Some(on_show_qr_code) = props.on_show_qr_code {
+            on_show_qr_code.call(());
+        }
+
+        is_qr_code_shown.set(true);
+    };
+
+    let handle_split = move |_| {
+        let (Some((threshold, total)), Some(payload)) = (split(), prepare_payload.call(())) else {
+            return;
+        };
+
+        match split_receiver_data(&payload, threshold, total) {
+            Ok(shares) => split_shares.set(Some(shares)),
+
+            Err(e) => {
+                tracing::error!("error splitting receiver data: {:?}", e);
+            }
+        }
+    };
+
+    let mut hand_out_share = move |index: usize| {
+        if !handed_out_shares.read().contains(&index) {
+            handed_out_shares.write().push(index);
+        }
+    };
+
+    let is_split_invalid = match split() {
+        Some((threshold, total)) => threshold < 2 || threshold > total || total > MAX_SPLIT_SHARES,
+        None => false,
+    };
+
+    let is_handed_out = match split_shares() {
+        Some(shares) => handed_out_shares.read().len() == shares.len(),
+        None => is_copied() || is_qr_code_shown(),
+    };
+
+    // the passphrase and the split can't change once the data went out with them
+    let is_shared = share_payload.read().is_some();
+
     rsx! {
         Modal {
             open: state().is_open(),
@@ -85,40 +225,263 @@ pub fn ShareReceiverDataDialog(props: ShareReceiverDataDialogProps) -> Element {
             ModalContent { class: "",
 
                 CardHeader { class: "mb-6",
-                    CardTitle { "Secret Created !" }
+                    CardTitle {
+                        if props.is_reshare {
+                            "Share Receiver Data"
+                        } else {
+                            "Secret Created !"
+                        }
+                    }
 
-                    CardDescription {
-                        "The intended recipient should now load the reference to your secret in order to periodically check its state, and recover it once unlocked. Use the button below to copy the receiver data and send it through a secure channel."
+                    if props.label.is_some() {
+                        CardDescription {
+                            "Copy the receiver data or show it as a QR code to load it on another device, or to keep a backup of it."
+                        }
+                    } else {
+                        CardDescription {
+                            "The intended recipient should now load the reference to your secret in order to periodically check its state, and recover it once unlocked. Use the buttons below to copy the receiver data and send it through a secure channel, or to show it as a QR code for the recipient to scan."
+                        }
                     }
                 }
 
                 CardContent { class: "",
-                    div { class: "flex items-center gap-4 mb-6 rounded-md border border-destructive bg-destructive/10 px-4 py-2 text-sm text-destructive",
-                        TriangleAlert { class: "h-4 w-4 shrink-0" }
-                        span {
-                            "Do NOT close before copied and secured. If you do, your secret will be lost forever !"
+                    if !props.is_reshare {
+                        div { class: "flex items-center gap-4 mb-6 rounded-md border border-destructive bg-destructive/10 px-4 py-2 text-sm text-destructive",
+                            TriangleAlert { class: "h-4 w-4 shrink-0" }
+                            span {
+                                "Do NOT close before copied and secured. If you do, your secret will be lost forever !"
+                            }
+                        }
+                    }
+
+                    if !props.skipped_keys.is_empty() {
+                        div { class: "flex gap-4 mb-6 rounded-md border border-orange-500 bg-orange-500/10 px-4 py-2 text-sm text-orange-500",
+                            TriangleAlert { class: "h-4 w-4 mt-0.5 shrink-0" }
+                            div { class: "flex flex-col gap-1 min-w-0",
+                                span {
+                                    "The secret was created without the keys of the servers below, it can't be recovered through them."
+                                }
+
+                                for outcome in props.skipped_keys.iter() {
+                                    span { class: "break-words",
+                                        "{outcome.server.host}, failed after {outcome.attempts} attempt(s): {outcome.error.clone().unwrap_or_default()}"
+                                    }
+                                }
+                            }
+                        }
+                    }
+
+                    div { class: "flex flex-col gap-2 mb-4",
+                        div { class: "flex items-center gap-2 text-sm",
+                            Button {
+                                r#type: "button",
+                                variant: ButtonVariant::Ghost,
+                                class: "px-0",
+                                disabled: is_shared,
+                                onclick: move |_| {
+                                    passphrase
+                                        .set(match passphrase() {
+                                            Some(_) => None,
+                                            None => Some(String::new()),
+                                        });
+                                    confirm_passphrase.set(String::new());
+                                },
+                                LockKeyhole { class: if passphrase().is_some() { "stroke-primary" } else { "" } }
+                            }
+
+                            span { class: "text-muted-foreground", "Encrypt with a passphrase" }
+
+                            HoverCard {
+                                HoverCardTrigger {
+                                    Info { class: "size-4 text-blue-500" }
+                                }
+
+                                HoverCardContent { class: "max-w-50",
+                                    "Recommended when the data goes through a clipboard manager or a chat app. Give the passphrase to the recipient through another channel."
+                                }
+                            }
+                        }
+
+                        if let Some(value) = passphrase() {
+                            div { class: "grid grid-cols-2 gap-2",
+                                Input {
+                                    r#type: "password",
+                                    placeholder: "Passphrase",
+                                    disabled: is_shared,
+                                    value,
+                                    oninput: move |e: FormEvent| passphrase.set(Some(e.value())),
+                                }
+                                Input {
+                                    r#type: "password",
+                                    placeholder: "Confirm passphrase",
+                                    disabled: is_shared,
+                                    value: confirm_passphrase(),
+                                    oninput: move |e: FormEvent| confirm_passphrase.set(e.value()),
+                                }
+                            }
+                        }
+                    }
+
+                    div { class: "flex flex-col gap-2 mb-4",
+                        div { class: "flex items-center gap-2 text-sm",
+                            Button {
+                                r#type: "button",
+                                variant: ButtonVariant::Ghost,
+                                class: "px-0",
+                                disabled: is_shared,
+                                onclick: move |_| {
+                                    split
+                                        .set(match split() {
+                                            Some(_) => None,
+                                            None => Some((2, 3)),
+                                        });
+                                },
+                                Users { class: if split().is_some() { "stroke-primary" } else { "" } }
+                            }
+
+                            span { class: "text-muted-foreground", "Split between several people" }
+
+                            HoverCard {
+                                HoverCardTrigger {
+                                    Info { class: "size-4 text-blue-500" }
+                                }
+
+                                HoverCardContent { class: "max-w-50",
+                                    "Each person gets a share, none of them can read the secret alone. Any number of them reaching the threshold can rebuild the receiver data together."
+                                }
+                            }
+                        }
+
+                        if let Some((threshold, total)) = split() {
+                            div { class: "flex items-center gap-2 text-sm",
+                                Input {
+                                    r#type: "number",
+                                    min: "2",
+                                    max: "{total}",
+                                    class: "w-20",
+                                    disabled: is_shared,
+                                    value: "{threshold}",
+                                    oninput: move |e: FormEvent| {
+                                        split.set(Some((e.value().parse().unwrap_or_default(), total)));
+                                    },
+                                }
+
+                                span { class: "shrink-0 text-muted-foreground", "shares required out of" }
+
+                                Input {
+                                    r#type: "number",
+                                    min: "2",
+                                    max: "{MAX_SPLIT_SHARES}",
+                                    class: "w-20",
+                                    disabled: is_shared,
+                                    value: "{total}",
+                                    oninput: move |e: FormEvent| {
+                                        split.set(Some((threshold, e.value().parse().unwrap_or_default())));
+                                    },
+                                }
+                            }
+                        }
+                    }
+
+                    if let (Some(shares), Some((threshold, total))) = (split_shares(), split()) {
+                        div { class: "flex flex-col gap-2 mb-4",
+                            span { class: "text-sm text-muted-foreground",
+                                "Any {threshold} of these {total} shares rebuild the receiver data, fewer reveal nothing about it. Give each share to a different person."
+                            }
+
+                            for (index , share) in shares.iter().cloned().enumerate() {
+                                div {
+                                    key: "{index}",
+                                    class: "flex items-center gap-2 rounded-md border border-border px-3 py-1 text-sm",
+                                    span { class: "grow", "Share {index + 1} of {total}" }
+
+                                    if handed_out_shares.read().contains(&index) {
+                                        CheckCheck { class: "h-4 w-4 shrink-0 stroke-green-500" }
+                                    }
+
+                                    Button {
+                                        variant: ButtonVariant::Ghost,
+                                        class: "px-0",
+                                        onclick: move |_| {
+                                            props.on_copy.call(share.clone());
+                                            hand_out_share(index);
+                                        },
+                                        Copy { class: "" }
+                                    }
+
+                                    Button {
+                                        variant: ButtonVariant::Ghost,
+                                        class: "px-0",
+                                        onclick: move |_| {
+                                            if let Some(on_show_qr_code) = props.on_show_qr_code {
+                                                on_show_qr_code.call(());
+                                            }
+
+                                            shown_share.set(Some(index));
+                                            hand_out_share(index);
+                                        },
+                                        QrCode { class: if shown_share() == Some(index) { "stroke-primary" } else { "" } }
+                                    }
+                                }
+                            }
+
+                            if let Some(payload) = shown_share().and_then(|index| shares.get(index).cloned()) {
+                                div { class: "flex justify-center mt-2",
+                                    QrCodeParts { key: "{payload}", payload }
+                                }
+                            }
+                        }
+                    }
+
+                    if is_qr_code_shown() {
+                        if let Some(payload) = share_payload() {
+                            div { class: "flex justify-center mb-4",
+                                QrCodeParts { payload }
+                            }
                         }
                     }
 
                     div { class: "flex items-center justify-between gap-4 w-full mt-4",
-                        Button {
-                            variant: ButtonVariant::Outline,
-                            onclick: handle_copy,
-                            disabled: is_copied(),
-                            class: "flex items-center justify-between w-max gap-4",
-                            if is_copied() {
-                                "Data Copied"
-                                ClipboardCheck { class: "h-4 w-4 shrink-0" }
-                            } else {
-                                "Copy Data"
-                                Copy { class: "" }
+                        if split().is_some() {
+                            Button {
+                                variant: ButtonVariant::Outline,
+                                onclick: handle_split,
+                                disabled: split_shares().is_some() || is_passphrase_invalid || is_split_invalid,
+                                class: "flex items-center justify-between w-max gap-4",
+                                "Create Shares"
+                                Users { class: "" }
+                            }
+                        } else {
+                            Button {
+                                variant: ButtonVariant::Outline,
+                                onclick: handle_copy,
+                                disabled: is_copied() || is_passphrase_invalid,
+                                class: "flex items-center justify-between w-max gap-4",
+                                if is_copied() {
+                                    "Data Copied"
+                                    ClipboardCheck { class: "h-4 w-4 shrink-0" }
+                                } else {
+                                    "Copy Data"
+                                    Copy { class: "" }
+                                }
+                            }
+
+                            if props.receiver.is_some() {
+                                Button {
+                                    variant: ButtonVariant::Outline,
+                                    onclick: handle_show_qr_code,
+                                    disabled: is_qr_code_shown() || is_passphrase_invalid,
+                                    class: "flex items-center justify-between w-max gap-4",
+                                    "Show QR"
+                                    QrCode { class: "" }
+                                }
                             }
                         }
 
                         Button {
                             variant: ButtonVariant::Destructive,
                             onclick: move |_| state().set_is_open(false),
-                            disabled: !is_copied(),
+                            disabled: !props.is_reshare && !is_handed_out,
                             class: "flex items-center justify-between w-max gap-4",
                             "Close"
                             X { class: "" }
@@ -0,0 +1,40 @@
+use dioxus::prelude::*;
+
+use lucide_dioxus::{FileX, Trash2};
+
+use crate::types::RecordFailure;
+
+use crate::ui::button::{Button, ButtonVariant};
+
+#[derive(PartialEq, Props, Clone)]
+pub struct UnreadableRecordProps {
+    pub failure: RecordFailure,
+    pub on_remove: EventHandler<()>,
+}
+
+#[component]
+pub fn UnreadableRecord(
+    UnreadableRecordProps { failure, on_remove }: UnreadableRecordProps,
+) -> Element {
+    rsx! {
+        div { class: "flex items-center justify-between gap-2 rounded-lg border border-destructive/40 bg-destructive/5 p-2.5",
+            div { class: "flex grow items-center gap-2.5 min-w-0",
+                div { class: "flex size-9 items-center justify-center shrink-0 rounded-md bg-muted/50",
+                    FileX { class: "stroke-destructive" }
+                }
+
+                div { class: "grid min-w-0 text-sm",
+                    span { class: "truncate font-semibold", "Unreadable secret" }
+                    span { class: "truncate text-xs text-muted-foreground", "{failure.error}" }
+                }
+            }
+
+            Button {
+                variant: ButtonVariant::Ghost,
+                class: "px-0 shrink-0",
+                onclick: move |_| on_remove.call(()),
+                Trash2 { class: "stroke-destructive" }
+            }
+        }
+    }
+}
@@ -0,0 +1,158 @@
+use dioxus::prelude::*;
+
+use lucide_dioxus::{CircleAlert, Plus, ShieldCheck, Trash2};
+
+use wingedcap::client::{get_vault_conf, KeyIndexArray, ServerWithMeta, VaultConf};
+
+use crate::ui::button::{Button, ButtonVariant};
+
+use crate::utils::validate_vault_sets;
+
+#[derive(Props, PartialEq, Clone)]
+pub struct VaultSetsEditorProps {
+    keys: Vec<ServerWithMeta>,
+    sets: Vec<KeyIndexArray>,
+    #[props(into)]
+    on_change: Callback<Vec<KeyIndexArray>>,
+}
+
+// one row per set and one column per key, any complete row unlocks the secret
+#[component]
+pub fn VaultSetsEditor(
+    VaultSetsEditorProps {
+        keys,
+        sets,
+        on_change,
+    }: VaultSetsEditorProps,
+) -> Element {
+    let total_keys = keys.len() as u64;
+
+    let validation = validate_vault_sets(&sets, total_keys);
+
+    let vault_conf_description = match get_vault_conf(sets.clone()) {
+        VaultConf::Standard { total, required } => {
+            format!("Standard {required} of {total}")
+        }
+        VaultConf::Custom => "Custom".to_string(),
+    };
+
+    let sets_for_toggle = sets.clone();
+
+    let handle_toggle = move |set_index: usize, key_index: u64| {
+        let mut sets = sets_for_toggle.clone();
+
+        let set = &mut sets[set_index];
+
+        match set.iter().position(|index| *index == key_index) {
+            Some(position) => {
+                set.remove(position);
+            }
+            None => {
+                set.push(key_index);
+                set.sort();
+            }
+        }
+
+        on_change.call(sets);
+    };
+
+    let sets_for_remove = sets.clone();
+
+    let handle_remove_set = move |set_index: usize| {
+        let mut sets = sets_for_remove.clone();
+
+        sets.remove(set_index);
+
+        on_change.call(sets);
+    };
+
+    let sets_for_add = sets.clone();
+
+    let handle_add_set = move |_| {
+        let mut sets = sets_for_add.clone();
+
+        sets.push(vec![]);
+
+        on_change.call(sets);
+    };
+
+    let grid_style =
+        format!("grid-template-columns: auto repeat({total_keys}, minmax(0, 2rem)) auto");
+
+    rsx! {
+        div { class: "flex flex-col gap-2 text-sm",
+            div { class: "grid items-center gap-x-2 gap-y-1 w-max", style: "{grid_style}",
+                span {}
+
+                for (key_index , key) in keys.iter().enumerate() {
+                    span {
+                        class: "text-center text-xs font-semibold text-muted-foreground",
+                        title: "{key.host}",
+                        "{key_index + 1}"
+                    }
+                }
+
+                span {}
+
+                for (set_index , set) in sets.iter().enumerate() {
+                    span { class: "pr-2 text-muted-foreground", "Set {set_index + 1}" }
+
+                    for key_index in 0..total_keys {
+                        {
+                            let handle_toggle = handle_toggle.clone();
+
+                            rsx! {
+                                input {
+                                    r#type: "checkbox",
+                                    class: "size-4 justify-self-center accent-primary cursor-pointer",
+                                    checked: set.contains(&key_index),
+                                    onchange: move |_| handle_toggle(set_index, key_index),
+                                }
+                            }
+                        }
+                    }
+
+                    {
+                        let handle_remove_set = handle_remove_set.clone();
+
+                        rsx! {
+                            Button {
+                                r#type: "button",
+                                variant: ButtonVariant::Ghost,
+                                class: "size-7 px-0",
+                                onclick: move |_| handle_remove_set(set_index),
+                                Trash2 { class: "size-4 text-destructive" }
+                            }
+                        }
+                    }
+                }
+            }
+
+            div { class: "flex items-center justify-between gap-2",
+                Button {
+                    r#type: "button",
+                    variant: ButtonVariant::Ghost,
+                    class: "h-7 gap-2 px-2",
+                    onclick: handle_add_set,
+                    Plus { class: "size-4" }
+                    span { "Add set" }
+                }
+
+                match validation {
+                    Ok(_) => rsx! {
+                        span { class: "flex items-center gap-1.5 text-muted-foreground",
+                            ShieldCheck { class: "size-4 stroke-green-500" }
+                            "{vault_conf_description}"
+                        }
+                    },
+                    Err(error) => rsx! {
+                        span { class: "flex items-center gap-1.5 text-destructive",
+                            CircleAlert { class: "size-4 shrink-0" }
+                            "{error}"
+                        }
+                    },
+                }
+            }
+        }
+    }
+}
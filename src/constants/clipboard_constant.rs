@@ -0,0 +1,4 @@
+pub const DEFAULT_CLIPBOARD_CLEAR_DELAY: u64 = 60;
+
+// receiver data longer than this tends to be cut or turned into a file by chat and note apps
+pub const MAX_RECEIVER_CLIPBOARD_LENGTH: usize = 64 * 1024;
@@ -0,0 +1,7 @@
+// a file payload starts with "WCFILE1 <json header>", its base64 content follows on the next line
+pub const FILE_ENVELOPE_PREFIX: &str = "WCFILE1";
+
+// every key set of the receiver data holds its own copy, so attachments have to stay small
+pub const MAX_FILE_ATTACHMENT_SIZE: u64 = 1024 * 1024;
+
+pub const DEFAULT_FILE_MIME_TYPE: &str = "application/octet-stream";
@@ -0,0 +1,26 @@
+pub const DEFAULT_HEARTBEAT_FRACTION: f64 = 0.5;
+
+pub const MIN_HEARTBEAT_INTERVAL: u64 = 1;
+
+// milliseconds between two checks for senders due for a ping
+pub const HEARTBEAT_SCHEDULER_TICK: u32 = 1000;
+
+// pings kept in the history of each sender
+pub const PING_HISTORY_MAX_LENGTH: usize = 50;
+
+// seconds before a projected unlock to warn the sender at
+pub const DEFAULT_UNLOCK_WARNINGS: [u64; 2] = [86400, 3600];
+
+pub const UNLOCK_WARNING_TOAST_DURATION: u32 = 30_000;
+
+// milliseconds between two checks of the projected unlock against the warnings
+pub const UNLOCK_WARNING_CHECK_INTERVAL: u32 = 10_000;
+
+// seconds between two polls of a receiver
+pub const DEFAULT_RECEIVER_POLL_INTERVAL: u64 = 60;
+
+// longest wait between two polls of a receiver that keeps failing
+pub const MAX_RECEIVER_POLL_BACKOFF: u64 = 3600;
+
+// milliseconds between two checks for receivers due for a poll
+pub const RECEIVER_POLL_TICK: u32 = 1000;
@@ -0,0 +1,16 @@
+use crate::types::{KdfAlgorithm, KdfParams};
+
+// argon2id 1.3 with the parameters recommended by OWASP, used for everything encrypted from now on
+pub const DEFAULT_KDF_PARAMS: KdfParams = KdfParams {
+    algorithm: KdfAlgorithm::Argon2id,
+    version: 0x13,
+    m_cost: 19 * 1024,
+    t_cost: 2,
+    p_cost: 1,
+};
+
+// params come along with shares and messages from other people, these keep a crafted one
+// from tying up the device
+pub const MAX_KDF_M_COST: u32 = 1024 * 1024;
+pub const MAX_KDF_T_COST: u32 = 16;
+pub const MAX_KDF_P_COST: u32 = 16;
@@ -1,3 +1,33 @@
 pub mod app_name_constant;
 #[cfg(feature = "desktop")]
 pub use app_name_constant::*;
+
+pub mod heartbeat_constant;
+pub use heartbeat_constant::*;
+
+pub mod clipboard_constant;
+pub use clipboard_constant::*;
+
+pub mod set_secret_constant;
+pub use set_secret_constant::*;
+
+pub mod vault_constant;
+pub use vault_constant::*;
+
+pub mod qr_constant;
+pub use qr_constant::*;
+
+pub mod file_envelope_constant;
+pub use file_envelope_constant::*;
+
+pub mod passphrase_envelope_constant;
+pub use passphrase_envelope_constant::*;
+
+pub mod receiver_share_constant;
+pub use receiver_share_constant::*;
+
+pub mod split_share_constant;
+pub use split_share_constant::*;
+
+pub mod kdf_constant;
+pub use kdf_constant::*;
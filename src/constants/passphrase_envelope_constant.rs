@@ -0,0 +1,3 @@
+// a message encrypted for the recipient reads "WCPASS1 <kdf> <salt> <encrypted message>", the kdf
+// as written by format_kdf_params, the others in base64
+pub const PASSPHRASE_ENVELOPE_PREFIX: &str = "WCPASS1";
@@ -0,0 +1,8 @@
+// parts are prefixed with "WCQR <part>/<total> " when a payload needs more than one code
+pub const QR_PART_PREFIX: &str = "WCQR";
+
+// characters per code, small enough for phone cameras to read reliably
+pub const QR_PART_MAX_LENGTH: usize = 600;
+
+// beyond this many codes, scanning the receiver data is more trouble than it's worth
+pub const MAX_RECEIVER_QR_PARTS: usize = 10;
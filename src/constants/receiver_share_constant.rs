@@ -0,0 +1,14 @@
+// an encrypted receiver share is an armored block:
+//
+// -----BEGIN WINGEDCAP RECEIVER-----
+// Version: 1
+// Kdf: <key derivation params, as written by format_kdf_params>
+//
+// <salt, nonce and encrypted receiver data in base64, wrapped>
+// -----END WINGEDCAP RECEIVER-----
+pub const RECEIVER_SHARE_BEGIN: &str = "-----BEGIN WINGEDCAP RECEIVER-----";
+pub const RECEIVER_SHARE_END: &str = "-----END WINGEDCAP RECEIVER-----";
+
+pub const RECEIVER_SHARE_VERSION: u32 = 1;
+
+pub const RECEIVER_SHARE_LINE_LENGTH: usize = 64;
@@ -0,0 +1,7 @@
+pub const DEFAULT_SET_KEY_RETRIES: u32 = 2;
+
+// milliseconds before the first retry, doubled on each following one
+pub const DEFAULT_SET_KEY_BACKOFF: u32 = 500;
+
+// key ids are 32 random bytes in hex
+pub const KEY_ID_LENGTH: usize = 64;
@@ -0,0 +1,5 @@
+// a share of split receiver data reads "WCSPLIT1 <split id> <threshold> <index>/<total> <share data in base64>"
+pub const SPLIT_SHARE_PREFIX: &str = "WCSPLIT1";
+
+// every share is as large as the receiver data itself, handing out more gets unwieldy
+pub const MAX_SPLIT_SHARES: u8 = 16;
@@ -0,0 +1,5 @@
+// seconds without interaction before an unlocked vault locks again
+pub const DEFAULT_VAULT_AUTO_LOCK: u64 = 300;
+
+// encrypted with the vault key to tell a wrong passphrase apart from a right one
+pub const VAULT_CHECK_PLAINTEXT: &str = "wingedcap-vault";
@@ -0,0 +1,9 @@
+pub mod types;
+
+pub mod constants;
+
+pub mod utils;
+
+pub mod storage;
+
+pub mod manager;
@@ -1,21 +1,18 @@
 #![windows_subsystem = "windows"]
 
-mod app;
-
-mod types;
-
-mod constants;
+#[cfg(any(feature = "desktop", feature = "web", feature = "mobile"))]
+use wingedcap_client::{constants, manager, storage, types, utils};
 
-mod utils;
-
-mod storage;
-
-mod manager;
+#[cfg(any(feature = "desktop", feature = "web", feature = "mobile"))]
+mod app;
 
+#[cfg(any(feature = "desktop", feature = "web", feature = "mobile"))]
 mod ui;
 
+#[cfg(any(feature = "desktop", feature = "web", feature = "mobile"))]
 mod components;
 
+#[cfg(any(feature = "desktop", feature = "web", feature = "mobile"))]
 mod views;
 
 fn main() {
@@ -24,7 +21,7 @@ fn main() {
         use dioxus::desktop::{LogicalSize, WindowBuilder};
 
         let window = WindowBuilder::new()
-            .with_title(crate::constants::APP_NAME.to_string())
+            .with_title(constants::APP_NAME.to_string())
             .with_inner_size(LogicalSize::new(800.0, 800.0))
             .with_min_inner_size(LogicalSize::new(400.0, 800.0));
 
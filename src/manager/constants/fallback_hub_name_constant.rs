@@ -0,0 +1 @@
+pub const FALLBACK_HUB_NAME: &str = "wingedcap";
@@ -1 +1 @@
-pub const HUB_HOST: &str = env!("HUB_HOST");
+pub const HUB_HOST: Option<&str> = option_env!("HUB_HOST");
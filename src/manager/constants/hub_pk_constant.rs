@@ -1 +1 @@
-pub const HUB_PK: &str = env!("HUB_PK");
+pub const HUB_PK: Option<&str> = option_env!("HUB_PK");
@@ -3,3 +3,6 @@ pub use hub_host_constant::*;
 
 pub mod hub_pk_constant;
 pub use hub_pk_constant::*;
+
+pub mod fallback_hub_name_constant;
+pub use fallback_hub_name_constant::*;
@@ -0,0 +1,16 @@
+use crate::storage::get_settings;
+use crate::types::Hub;
+
+use super::get_hubs;
+
+pub fn get_default_hub() -> Result<Hub, String> {
+    let hubs = get_hubs();
+
+    let default_hub_name = get_settings().unwrap_or_default().hubs.default_hub;
+
+    let default_hub = default_hub_name
+        .and_then(|name| hubs.iter().find(|hub| hub.name == name).cloned())
+        .or(hubs.first().cloned());
+
+    default_hub.ok_or("no hub configured".to_string())
+}
@@ -0,0 +1,15 @@
+use crate::types::Hub;
+
+use super::super::{FALLBACK_HUB_NAME, HUB_HOST, HUB_PK};
+
+pub fn get_fallback_hub() -> Option<Hub> {
+    match (HUB_HOST, HUB_PK) {
+        (Some(host), Some(pk)) if !host.is_empty() && !pk.is_empty() => Some(Hub {
+            name: FALLBACK_HUB_NAME.to_string(),
+            host: host.to_string(),
+            pk: pk.to_string(),
+        }),
+
+        _ => None,
+    }
+}
@@ -0,0 +1,16 @@
+use crate::storage::get_settings;
+use crate::types::{Hub, HubSettings};
+
+use super::get_fallback_hub;
+
+pub fn get_hubs() -> Vec<Hub> {
+    let HubSettings { mut hubs, .. } = get_settings().unwrap_or_default().hubs;
+
+    if let Some(fallback_hub) = get_fallback_hub() {
+        if !hubs.iter().any(|hub| hub.name == fallback_hub.name) {
+            hubs.push(fallback_hub);
+        }
+    }
+
+    hubs
+}
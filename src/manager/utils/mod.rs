@@ -1,2 +1,11 @@
 pub mod get_server_util;
 pub use get_server_util::*;
+
+pub mod get_fallback_hub_util;
+pub use get_fallback_hub_util::*;
+
+pub mod get_hubs_util;
+pub use get_hubs_util::*;
+
+pub mod get_default_hub_util;
+pub use get_default_hub_util::*;
@@ -0,0 +1 @@
+pub const BACKUP_FORMAT: &str = "wingedcap-backup";
@@ -0,0 +1,2 @@
+// bump whenever BackupContent changes shape
+pub const BACKUP_VERSION: u32 = 1;
@@ -0,0 +1 @@
+pub const INBOX_STORAGE_NAME_PREFIX: &str = "inbox";
@@ -3,3 +3,33 @@ pub use sender_storage_name_prefix_constant::*;
 
 pub mod receiver_storage_name_prefix_constant;
 pub use receiver_storage_name_prefix_constant::*;
+
+pub mod sender_meta_storage_name_prefix_constant;
+pub use sender_meta_storage_name_prefix_constant::*;
+
+pub mod receiver_meta_storage_name_prefix_constant;
+pub use receiver_meta_storage_name_prefix_constant::*;
+
+pub mod settings_storage_name_constant;
+pub use settings_storage_name_constant::*;
+
+pub mod vault_storage_name_constant;
+pub use vault_storage_name_constant::*;
+
+pub mod record_version_constant;
+pub use record_version_constant::*;
+
+pub mod record_migrations_constant;
+pub use record_migrations_constant::*;
+
+pub mod backup_format_constant;
+pub use backup_format_constant::*;
+
+pub mod backup_version_constant;
+pub use backup_version_constant::*;
+
+pub mod sender_bundle_storage_name_prefix_constant;
+pub use sender_bundle_storage_name_prefix_constant::*;
+
+pub mod inbox_storage_name_prefix_constant;
+pub use inbox_storage_name_prefix_constant::*;
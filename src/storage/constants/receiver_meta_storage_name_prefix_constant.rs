@@ -0,0 +1,2 @@
+// records are listed by pattern, so it must not contain the receiver prefix
+pub const RECEIVER_META_STORAGE_NAME_PREFIX: &str = "rmeta";
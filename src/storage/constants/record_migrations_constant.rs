@@ -0,0 +1,9 @@
+use crate::types::RecordMigration;
+
+use super::super::migrations::wrap_unversioned_record;
+
+// ordered by version, each one upgrades a record from `from_version` to the next version
+pub const RECORD_MIGRATIONS: &[RecordMigration] = &[RecordMigration {
+    from_version: 0,
+    migrate: wrap_unversioned_record,
+}];
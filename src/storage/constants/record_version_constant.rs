@@ -0,0 +1,2 @@
+// bump along with a new entry in RECORD_MIGRATIONS whenever stored records change shape
+pub const RECORD_VERSION: u32 = 1;
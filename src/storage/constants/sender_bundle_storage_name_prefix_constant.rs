@@ -0,0 +1 @@
+pub const SENDER_BUNDLE_STORAGE_NAME_PREFIX: &str = "bundle";
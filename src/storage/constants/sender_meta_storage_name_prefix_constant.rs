@@ -0,0 +1 @@
+pub const SENDER_META_STORAGE_NAME_PREFIX: &str = "meta";
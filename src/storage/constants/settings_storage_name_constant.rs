@@ -0,0 +1 @@
+pub const SETTINGS_STORAGE_NAME: &str = "settings";
@@ -0,0 +1 @@
+pub const VAULT_STORAGE_NAME: &str = "vault";
@@ -0,0 +1,2 @@
+pub mod wrap_unversioned_record_migration;
+pub use wrap_unversioned_record_migration::*;
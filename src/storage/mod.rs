@@ -1,4 +1,6 @@
 pub mod constants;
 
+pub mod migrations;
+
 pub mod utils;
 pub use utils::*;
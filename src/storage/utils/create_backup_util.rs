@@ -0,0 +1,90 @@
+use base64::{engine::general_purpose::STANDARD, Engine};
+
+use wingedcap::sha_256;
+
+use crate::types::{BackupContent, BackupFile, BackupReceiver, BackupSender, RecordData};
+
+use crate::utils::{derive_vault_key, vault_encrypt};
+
+use crate::constants::DEFAULT_KDF_PARAMS;
+
+use super::super::constants::{BACKUP_FORMAT, BACKUP_VERSION};
+
+use super::{
+    get_inbox_entry, get_receiver_meta, get_receivers, get_sender_bundle, get_sender_meta,
+    get_senders, get_settings,
+};
+
+// every sender and receiver with their meta, bundles and saved messages along with the settings,
+// encrypted when a passphrase is given
+pub fn create_backup(passphrase: Option<&str>) -> Result<BackupFile, String> {
+    let senders = get_senders()?;
+    let receivers = get_receivers()?;
+
+    let unreadable = senders.failures.len() + receivers.failures.len();
+
+    if unreadable > 0 {
+        return Err(format!(
+            "{} stored records are unreadable, remove them before backing up",
+            unreadable
+        ));
+    }
+
+    let content = BackupContent {
+        senders: senders
+            .records
+            .into_iter()
+            .map(|(_, sender)| {
+                Ok(BackupSender {
+                    meta: get_sender_meta(&sender).ok(),
+                    bundle: get_sender_bundle(&sender)?,
+                    sender,
+                })
+            })
+            .collect::<Result<_, String>>()?,
+        receivers: receivers
+            .records
+            .into_iter()
+            .map(|(_, receiver)| {
+                Ok(BackupReceiver {
+                    meta: get_receiver_meta(&receiver).ok(),
+                    inbox_entry: get_inbox_entry(&receiver)?,
+                    receiver,
+                })
+            })
+            .collect::<Result<_, String>>()?,
+        settings: get_settings()?,
+    };
+
+    // hashed as a json value so that the checksum survives fields added to the content later on
+    let content = serde_json::to_value(&content).map_err(|e| e.to_string())?;
+
+    let content_json = serde_json::to_string(&content).map_err(|e| e.to_string())?;
+
+    let checksum = sha_256(&content_json);
+
+    let (salt, kdf, data) = match passphrase {
+        Some(passphrase) if !passphrase.is_empty() => {
+            let salt: [u8; 16] = rand::random();
+
+            let key = derive_vault_key(passphrase, &salt, &DEFAULT_KDF_PARAMS)?;
+
+            (
+                Some(STANDARD.encode(salt)),
+                Some(DEFAULT_KDF_PARAMS),
+                RecordData::Encrypted(vault_encrypt(&key, &content_json)?),
+            )
+        }
+
+        _ => (None, None, RecordData::Plain(content)),
+    };
+
+    Ok(BackupFile {
+        format: BACKUP_FORMAT.to_string(),
+        version: BACKUP_VERSION,
+        salt,
+        kdf,
+        checksum,
+        data,
+    })
+}
@@ -0,0 +1,29 @@
+use serde_json::Value;
+
+use crate::types::{RecordData, RecordEnvelope};
+
+use crate::utils::vault_decrypt;
+
+use super::get_vault_key;
+
+// unwraps a stored record into its version and plain json
+pub fn decode_record(stored_record: Value) -> Result<(u32, Value), String> {
+    let (version, data) = match serde_json::from_value::<RecordEnvelope>(stored_record.clone()) {
+        Ok(RecordEnvelope { version, data }) => (version, data),
+
+        // records stored before versioning are plain json
+        Err(_) => (0, RecordData::Plain(stored_record)),
+    };
+
+    let record = match data {
+        RecordData::Plain(record) => record,
+
+        RecordData::Encrypted(encrypted) => {
+            let key = get_vault_key().ok_or("vault is locked")?;
+
+            serde_json::from_str(&vault_decrypt(&key, &encrypted)?).map_err(|e| e.to_string())?
+        }
+    };
+
+    Ok((version, record))
+}
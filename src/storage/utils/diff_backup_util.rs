@@ -0,0 +1,55 @@
+use crate::types::{BackupContent, BackupDiff, BackupRecordStatus};
+
+use super::{find_receiver_conflict, get_receiver_id, get_sender_id, get_senders};
+
+// how each record of the backup compares to the stored ones
+pub fn diff_backup(content: &BackupContent) -> Result<BackupDiff, String> {
+    let stored_senders = get_senders()?.records;
+
+    let mut senders = vec![];
+
+    for backup_sender in content.senders.iter() {
+        let id = get_sender_id(&backup_sender.sender)?;
+
+        let mut stored_sender = None;
+
+        for (_, sender) in stored_senders.iter() {
+            if get_sender_id(sender)? == id {
+                stored_sender = Some(sender);
+                break;
+            }
+        }
+
+        let status = match stored_sender {
+            None => BackupRecordStatus::New,
+            Some(sender) if *sender == backup_sender.sender => BackupRecordStatus::Identical,
+            Some(_) => BackupRecordStatus::Conflicting,
+        };
+
+        senders.push((backup_sender.clone(), status));
+    }
+
+    let mut receivers = vec![];
+
+    for backup_receiver in content.receivers.iter() {
+        let receiver = &backup_receiver.receiver;
+
+        let id = get_receiver_id(receiver)?;
+
+        // a different secret under the same label is stored alongside, so it counts as new
+        let status = match find_receiver_conflict(receiver)? {
+            Some((_, existing)) if get_receiver_id(&existing)? == id => {
+                if existing.label == receiver.label {
+                    BackupRecordStatus::Identical
+                } else {
+                    BackupRecordStatus::Conflicting
+                }
+            }
+            _ => BackupRecordStatus::New,
+        };
+
+        receivers.push((backup_receiver.clone(), status));
+    }
+
+    Ok(BackupDiff { senders, receivers })
+}
@@ -0,0 +1,58 @@
+use cross_storage::storage_del;
+
+use crate::types::{InboxEntry, SenderBundle};
+
+use super::super::constants::{
+    INBOX_STORAGE_NAME_PREFIX, SENDER_BUNDLE_STORAGE_NAME_PREFIX, VAULT_STORAGE_NAME,
+};
+
+use super::{
+    get_receivers, get_records, get_senders, is_vault_locked, set_vault_key, store_record,
+};
+
+// turns the vault off, storing every record as plain json again
+pub fn disable_vault() -> Result<(), String> {
+    if is_vault_locked() {
+        return Err("vault is locked".to_string());
+    }
+
+    let senders = get_senders()?;
+    let receivers = get_receivers()?;
+    let bundles = get_records::<SenderBundle>(SENDER_BUNDLE_STORAGE_NAME_PREFIX)?;
+    let inbox = get_records::<InboxEntry>(INBOX_STORAGE_NAME_PREFIX)?;
+
+    // they would be lost for good once the vault key is gone
+    let failures_count = senders.failures.len()
+        + receivers.failures.len()
+        + bundles.failures.len()
+        + inbox.failures.len();
+
+    if failures_count > 0 {
+        return Err(format!(
+            "{} stored secret(s) can't be read, remove them before disabling the vault",
+            failures_count
+        ));
+    }
+
+    storage_del(VAULT_STORAGE_NAME)?;
+
+    set_vault_key(None);
+
+    for (storage_id, sender) in senders.records {
+        store_record(&storage_id, sender)?;
+    }
+
+    for (storage_id, receiver) in receivers.records {
+        store_record(&storage_id, receiver)?;
+    }
+
+    for (storage_id, bundle) in bundles.records {
+        store_record(&storage_id, bundle)?;
+    }
+
+    for (storage_id, entry) in inbox.records {
+        store_record(&storage_id, entry)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,80 @@
+use base64::{engine::general_purpose::STANDARD, Engine};
+
+use cross_storage::storage_set_object;
+
+use crate::constants::{DEFAULT_KDF_PARAMS, VAULT_CHECK_PLAINTEXT};
+
+use crate::types::{InboxEntry, SenderBundle, VaultHeader};
+
+use crate::utils::{derive_vault_key, vault_encrypt};
+
+use super::super::constants::{
+    INBOX_STORAGE_NAME_PREFIX, SENDER_BUNDLE_STORAGE_NAME_PREFIX, VAULT_STORAGE_NAME,
+};
+
+use super::{
+    get_receivers, get_records, get_senders, is_vault_enabled, set_vault_key, store_record,
+};
+
+// turns the vault on and encrypts every stored record with the new passphrase; the sender and
+// receiver meta (ping history, unlock times) stay in plain json so pings and polls can record
+// them while the vault is locked
+pub fn enable_vault(passphrase: &str) -> Result<(), String> {
+    if is_vault_enabled() {
+        return Err("vault is already enabled".to_string());
+    }
+
+    if passphrase.is_empty() {
+        return Err("passphrase is empty".to_string());
+    }
+
+    let senders = get_senders()?;
+    let receivers = get_receivers()?;
+    let bundles = get_records::<SenderBundle>(SENDER_BUNDLE_STORAGE_NAME_PREFIX)?;
+    let inbox = get_records::<InboxEntry>(INBOX_STORAGE_NAME_PREFIX)?;
+
+    // they would stay in plain json while the vault shows as enabled
+    let failures_count = senders.failures.len()
+        + receivers.failures.len()
+        + bundles.failures.len()
+        + inbox.failures.len();
+
+    if failures_count > 0 {
+        return Err(format!(
+            "{} stored secret(s) can't be read, remove them before enabling the vault",
+            failures_count
+        ));
+    }
+
+    let salt: [u8; 16] = rand::random();
+
+    let key = derive_vault_key(passphrase, &salt, &DEFAULT_KDF_PARAMS)?;
+
+    let header = VaultHeader {
+        salt: STANDARD.encode(salt),
+        kdf: DEFAULT_KDF_PARAMS,
+        check: vault_encrypt(&key, VAULT_CHECK_PLAINTEXT)?,
+    };
+
+    storage_set_object(VAULT_STORAGE_NAME, header)?;
+
+    set_vault_key(Some(key));
+
+    for (storage_id, sender) in senders.records {
+        store_record(&storage_id, sender)?;
+    }
+
+    for (storage_id, receiver) in receivers.records {
+        store_record(&storage_id, receiver)?;
+    }
+
+    for (storage_id, bundle) in bundles.records {
+        store_record(&storage_id, bundle)?;
+    }
+
+    for (storage_id, entry) in inbox.records {
+        store_record(&storage_id, entry)?;
+    }
+
+    Ok(())
+}
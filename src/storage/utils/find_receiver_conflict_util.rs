@@ -0,0 +1,28 @@
+use wingedcap::client::ReceiverStored;
+
+use super::{get_receiver_id, get_receivers, get_storage_name};
+
+use super::super::constants::RECEIVER_STORAGE_NAME_PREFIX;
+
+// a stored receiver holding the same secret, or else one with the same label
+pub fn find_receiver_conflict(
+    receiver: &ReceiverStored,
+) -> Result<Option<(String, ReceiverStored)>, String> {
+    let storage_name = format!(
+        "{}_{}",
+        RECEIVER_STORAGE_NAME_PREFIX,
+        get_receiver_id(receiver)?
+    );
+
+    let receivers = get_receivers()?.records;
+
+    let same_secret = receivers
+        .iter()
+        .find(|(storage_id, _)| get_storage_name(storage_id) == storage_name);
+
+    let same_label = receivers
+        .iter()
+        .find(|(_, stored_receiver)| stored_receiver.label == receiver.label);
+
+    Ok(same_secret.or(same_label).cloned())
+}
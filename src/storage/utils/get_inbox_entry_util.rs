@@ -0,0 +1,28 @@
+use wingedcap::client::ReceiverStored;
+
+use cross_storage::storage_get;
+
+use crate::types::InboxEntry;
+
+use super::super::constants::INBOX_STORAGE_NAME_PREFIX;
+
+use super::{decode_record, get_receiver_id, migrate_record};
+
+// None until the decrypted messages of the receiver are saved
+pub fn get_inbox_entry(receiver: &ReceiverStored) -> Result<Option<InboxEntry>, String> {
+    let storage_id = get_receiver_id(receiver)?;
+
+    let storage_id = format!("{}_{}", INBOX_STORAGE_NAME_PREFIX, storage_id);
+
+    let Ok(entry_json) = storage_get(&storage_id) else {
+        return Ok(None);
+    };
+
+    let stored_entry = serde_json::from_str(&entry_json).map_err(|e| e.to_string())?;
+
+    let (version, entry) = decode_record(stored_entry)?;
+
+    let entry = migrate_record(INBOX_STORAGE_NAME_PREFIX, version, entry)?;
+
+    serde_json::from_value(entry).map_err(|e| e.to_string())
+}
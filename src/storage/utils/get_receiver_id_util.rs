@@ -0,0 +1,15 @@
+use wingedcap::{
+    client::{Receiver, ReceiverStored},
+    sha_256,
+};
+
+pub fn get_receiver_id(receiver: &ReceiverStored) -> Result<String, String> {
+    let ReceiverStored { keys, sets, .. } = receiver.clone();
+
+    let receiver_without_label = Receiver { keys, sets };
+
+    let receiver_without_label_json =
+        serde_json::to_string(&receiver_without_label).map_err(|e| e.to_string())?;
+
+    Ok(sha_256(&receiver_without_label_json))
+}
@@ -0,0 +1,19 @@
+use wingedcap::client::ReceiverStored;
+
+use cross_storage::storage_get;
+
+use crate::types::ReceiverMeta;
+
+use super::super::constants::RECEIVER_META_STORAGE_NAME_PREFIX;
+
+use super::get_receiver_id;
+
+pub fn get_receiver_meta(receiver: &ReceiverStored) -> Result<ReceiverMeta, String> {
+    let storage_id = get_receiver_id(receiver)?;
+
+    let storage_id = format!("{}_{}", RECEIVER_META_STORAGE_NAME_PREFIX, storage_id);
+
+    let meta_json = storage_get(&storage_id)?;
+
+    serde_json::from_str(&meta_json).map_err(|e| e.to_string())
+}
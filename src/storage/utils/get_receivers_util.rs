@@ -1,9 +1,11 @@
 use wingedcap::client::ReceiverStored;
 
-use cross_storage::storage_get_matches;
+use crate::types::LoadedRecords;
 
 use super::super::constants::RECEIVER_STORAGE_NAME_PREFIX;
 
-pub fn get_receivers() -> Result<Vec<(String, ReceiverStored)>, String> {
-    storage_get_matches(RECEIVER_STORAGE_NAME_PREFIX)
+use super::get_records;
+
+pub fn get_receivers() -> Result<LoadedRecords<ReceiverStored>, String> {
+    get_records(RECEIVER_STORAGE_NAME_PREFIX)
 }
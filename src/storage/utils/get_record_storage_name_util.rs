@@ -0,0 +1,24 @@
+use serde_json::Value;
+
+use wingedcap::client::{ReceiverStored, SenderStored};
+
+use super::super::constants::{RECEIVER_STORAGE_NAME_PREFIX, SENDER_STORAGE_NAME_PREFIX};
+
+use super::{get_receiver_id, get_sender_id};
+
+// the content-derived key a sender or receiver json belongs under
+pub fn get_record_storage_name(prefix: &str, record: &Value) -> Result<String, String> {
+    let id = match prefix {
+        SENDER_STORAGE_NAME_PREFIX => get_sender_id(
+            &serde_json::from_value::<SenderStored>(record.clone()).map_err(|e| e.to_string())?,
+        )?,
+
+        RECEIVER_STORAGE_NAME_PREFIX => get_receiver_id(
+            &serde_json::from_value::<ReceiverStored>(record.clone()).map_err(|e| e.to_string())?,
+        )?,
+
+        _ => return Err(format!("unknown record prefix \"{}\"", prefix)),
+    };
+
+    Ok(format!("{}_{}", prefix, id))
+}
@@ -0,0 +1,29 @@
+use serde::de::DeserializeOwned;
+
+use crate::types::{LoadedRecords, RecordFailure, StoredRecord};
+
+use super::{decode_record, get_stored_records, is_vault_locked, migrate_record};
+
+// reads senders or receivers, decrypting and migrating them to the current version
+pub fn get_records<T: DeserializeOwned>(prefix: &str) -> Result<LoadedRecords<T>, String> {
+    if is_vault_locked() {
+        return Err("vault is locked".to_string());
+    }
+
+    let mut records = vec![];
+    let mut failures = vec![];
+
+    for StoredRecord { storage_id, json } in get_stored_records(prefix)? {
+        let record = json
+            .and_then(decode_record)
+            .and_then(|(version, record)| migrate_record(prefix, version, record))
+            .and_then(|record| serde_json::from_value(record).map_err(|e| e.to_string()));
+
+        match record {
+            Ok(record) => records.push((storage_id, record)),
+            Err(error) => failures.push(RecordFailure { storage_id, error }),
+        }
+    }
+
+    Ok(LoadedRecords { records, failures })
+}
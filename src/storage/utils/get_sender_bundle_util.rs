@@ -0,0 +1,28 @@
+use wingedcap::client::SenderStored;
+
+use cross_storage::storage_get;
+
+use crate::types::SenderBundle;
+
+use super::super::constants::SENDER_BUNDLE_STORAGE_NAME_PREFIX;
+
+use super::{decode_record, get_sender_id, migrate_record};
+
+// None for senders created or imported without their receiver data
+pub fn get_sender_bundle(sender: &SenderStored) -> Result<Option<SenderBundle>, String> {
+    let storage_id = get_sender_id(sender)?;
+
+    let storage_id = format!("{}_{}", SENDER_BUNDLE_STORAGE_NAME_PREFIX, storage_id);
+
+    let Ok(bundle_json) = storage_get(&storage_id) else {
+        return Ok(None);
+    };
+
+    let stored_bundle = serde_json::from_str(&bundle_json).map_err(|e| e.to_string())?;
+
+    let (version, bundle) = decode_record(stored_bundle)?;
+
+    let bundle = migrate_record(SENDER_BUNDLE_STORAGE_NAME_PREFIX, version, bundle)?;
+
+    serde_json::from_value(bundle).map_err(|e| e.to_string())
+}
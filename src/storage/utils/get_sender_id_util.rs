@@ -0,0 +1,27 @@
+use wingedcap::{
+    client::{Key, Sender, SenderStored},
+    sha_256,
+};
+
+pub fn get_sender_id(sender: &SenderStored) -> Result<String, String> {
+    let SenderStored { keys, sets, .. } = sender.clone();
+
+    let keys_without_meta: Vec<Key> = keys
+        .iter()
+        .map(|key| Key {
+            host: key.host.clone(),
+            pk: key.pk.clone(),
+            id: key.id.clone(),
+        })
+        .collect();
+
+    let sender_without_meta = Sender {
+        keys: keys_without_meta,
+        sets,
+    };
+
+    let sender_without_meta_json =
+        serde_json::to_string(&sender_without_meta).map_err(|e| e.to_string())?;
+
+    Ok(sha_256(&sender_without_meta_json))
+}
@@ -0,0 +1,19 @@
+use wingedcap::client::SenderStored;
+
+use cross_storage::storage_get;
+
+use crate::types::SenderMeta;
+
+use super::super::constants::SENDER_META_STORAGE_NAME_PREFIX;
+
+use super::get_sender_id;
+
+pub fn get_sender_meta(sender: &SenderStored) -> Result<SenderMeta, String> {
+    let storage_id = get_sender_id(sender)?;
+
+    let storage_id = format!("{}_{}", SENDER_META_STORAGE_NAME_PREFIX, storage_id);
+
+    let meta_json = storage_get(&storage_id)?;
+
+    serde_json::from_str(&meta_json).map_err(|e| e.to_string())
+}
@@ -1,9 +1,11 @@
 use wingedcap::client::SenderStored;
 
-use cross_storage::storage_get_matches;
+use crate::types::LoadedRecords;
 
 use super::super::constants::SENDER_STORAGE_NAME_PREFIX;
 
-pub fn get_senders() -> Result<Vec<(String, SenderStored)>, String> {
-    storage_get_matches(SENDER_STORAGE_NAME_PREFIX)
+use super::get_records;
+
+pub fn get_senders() -> Result<LoadedRecords<SenderStored>, String> {
+    get_records(SENDER_STORAGE_NAME_PREFIX)
 }
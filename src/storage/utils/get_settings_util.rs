@@ -0,0 +1,13 @@
+use cross_storage::storage_get;
+
+use crate::types::Settings;
+
+use super::super::constants::SETTINGS_STORAGE_NAME;
+
+pub fn get_settings() -> Result<Settings, String> {
+    match storage_get(SETTINGS_STORAGE_NAME) {
+        Ok(settings_json) => serde_json::from_str(&settings_json).map_err(|e| e.to_string()),
+
+        Err(_) => Ok(Settings::default()),
+    }
+}
@@ -0,0 +1,4 @@
+// storage ids listed on native targets are full file paths, this keeps the key only
+pub fn get_storage_name(storage_id: &str) -> &str {
+    storage_id.rsplit(['/', '\\']).next().unwrap_or(storage_id)
+}
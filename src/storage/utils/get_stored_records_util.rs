@@ -0,0 +1,23 @@
+use regex::Regex;
+
+use cross_storage::{storage_get, storage_get_keys};
+
+use crate::types::StoredRecord;
+
+// raw json of every record matching the pattern, keeping the ones that can't be read or parsed
+pub fn get_stored_records(pattern: &str) -> Result<Vec<StoredRecord>, String> {
+    let regex = Regex::new(pattern).map_err(|e| e.to_string())?;
+
+    let stored_records = storage_get_keys()?
+        .into_iter()
+        .filter(|storage_id| regex.is_match(storage_id))
+        .map(|storage_id| {
+            let json = storage_get(&storage_id)
+                .and_then(|json| serde_json::from_str(&json).map_err(|e| e.to_string()));
+
+            StoredRecord { storage_id, json }
+        })
+        .collect();
+
+    Ok(stored_records)
+}
@@ -0,0 +1,15 @@
+use cross_storage::storage_get;
+
+use crate::types::VaultHeader;
+
+use super::super::constants::VAULT_STORAGE_NAME;
+
+pub fn get_vault_header() -> Result<Option<VaultHeader>, String> {
+    match storage_get(VAULT_STORAGE_NAME) {
+        Ok(header_json) => serde_json::from_str(&header_json)
+            .map(Some)
+            .map_err(|e| e.to_string()),
+
+        Err(_) => Ok(None),
+    }
+}
@@ -0,0 +1,5 @@
+use super::get_vault_header;
+
+pub fn is_vault_enabled() -> bool {
+    matches!(get_vault_header(), Ok(Some(_)))
+}
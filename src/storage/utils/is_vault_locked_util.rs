@@ -0,0 +1,5 @@
+use super::{get_vault_key, is_vault_enabled};
+
+pub fn is_vault_locked() -> bool {
+    is_vault_enabled() && get_vault_key().is_none()
+}
@@ -0,0 +1,5 @@
+use super::set_vault_key;
+
+pub fn lock_vault() {
+    set_vault_key(None);
+}
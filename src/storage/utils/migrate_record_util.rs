@@ -0,0 +1,23 @@
+use serde_json::Value;
+
+use super::super::constants::{RECORD_MIGRATIONS, RECORD_VERSION};
+
+pub fn migrate_record(prefix: &str, version: u32, record: Value) -> Result<Value, String> {
+    if version > RECORD_VERSION {
+        return Err(format!(
+            "record version {} is newer than the supported version {}",
+            version, RECORD_VERSION
+        ));
+    }
+
+    let mut record = record;
+
+    for migration in RECORD_MIGRATIONS
+        .iter()
+        .filter(|migration| migration.from_version >= version)
+    {
+        record = (migration.migrate)(prefix, record)?;
+    }
+
+    Ok(record)
+}
@@ -0,0 +1,54 @@
+use cross_storage::{storage_del, storage_get};
+
+use crate::types::{RecordFailure, StoredRecord};
+
+use super::super::constants::{
+    RECEIVER_STORAGE_NAME_PREFIX, RECORD_VERSION, SENDER_STORAGE_NAME_PREFIX,
+};
+
+use super::{
+    decode_record, get_record_storage_name, get_storage_name, get_stored_records, is_vault_locked,
+    migrate_record, store_record,
+};
+
+// upgrades every outdated sender and receiver in place and moves the ones stored under a
+// key that isn't derived from their content (e.g. receivers keyed by label), returns the
+// ones that couldn't be migrated
+pub fn migrate_records() -> Result<Vec<RecordFailure>, String> {
+    if is_vault_locked() {
+        return Err("vault is locked".to_string());
+    }
+
+    let mut failures = vec![];
+
+    for prefix in [SENDER_STORAGE_NAME_PREFIX, RECEIVER_STORAGE_NAME_PREFIX] {
+        for StoredRecord { storage_id, json } in get_stored_records(prefix)? {
+            let migration_result = json.and_then(decode_record).and_then(|(version, record)| {
+                let record = migrate_record(prefix, version, record)?;
+
+                let storage_name = get_record_storage_name(prefix, &record)?;
+
+                if storage_name == get_storage_name(&storage_id) {
+                    if version == RECORD_VERSION {
+                        return Ok(());
+                    }
+
+                    return store_record(&storage_id, record);
+                }
+
+                // an existing record under the content key holds the very same secret
+                if storage_get(&storage_name).is_err() {
+                    store_record(&storage_name, record)?;
+                }
+
+                storage_del(&storage_id)
+            });
+
+            if let Err(error) = migration_result {
+                failures.push(RecordFailure { storage_id, error });
+            }
+        }
+    }
+
+    Ok(failures)
+}
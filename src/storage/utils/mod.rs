@@ -1,11 +1,137 @@
+pub mod get_sender_id_util;
+pub use get_sender_id_util::*;
+
 pub mod store_sender_util;
 pub use store_sender_util::*;
 
 pub mod get_senders_util;
 pub use get_senders_util::*;
 
+pub mod store_sender_meta_util;
+pub use store_sender_meta_util::*;
+
+pub mod get_sender_meta_util;
+pub use get_sender_meta_util::*;
+
+pub mod remove_sender_meta_util;
+pub use remove_sender_meta_util::*;
+
+pub mod record_sender_ping_util;
+pub use record_sender_ping_util::*;
+
+pub mod get_receiver_meta_util;
+pub use get_receiver_meta_util::*;
+
+pub mod store_receiver_meta_util;
+pub use store_receiver_meta_util::*;
+
+pub mod remove_receiver_meta_util;
+pub use remove_receiver_meta_util::*;
+
+pub mod record_receiver_unlock_util;
+pub use record_receiver_unlock_util::*;
+
 pub mod store_receiver_util;
 pub use store_receiver_util::*;
 
 pub mod get_receivers_util;
 pub use get_receivers_util::*;
+
+pub mod get_settings_util;
+pub use get_settings_util::*;
+
+pub mod store_settings_util;
+pub use store_settings_util::*;
+
+pub mod vault_key_util;
+pub use vault_key_util::*;
+
+pub mod get_vault_header_util;
+pub use get_vault_header_util::*;
+
+pub mod is_vault_enabled_util;
+pub use is_vault_enabled_util::*;
+
+pub mod is_vault_locked_util;
+pub use is_vault_locked_util::*;
+
+pub mod lock_vault_util;
+pub use lock_vault_util::*;
+
+pub mod unlock_vault_util;
+pub use unlock_vault_util::*;
+
+pub mod enable_vault_util;
+pub use enable_vault_util::*;
+
+pub mod disable_vault_util;
+pub use disable_vault_util::*;
+
+pub mod store_record_util;
+pub use store_record_util::*;
+
+pub mod get_records_util;
+pub use get_records_util::*;
+
+pub mod get_stored_records_util;
+pub use get_stored_records_util::*;
+
+pub mod decode_record_util;
+pub use decode_record_util::*;
+
+pub mod migrate_record_util;
+pub use migrate_record_util::*;
+
+pub mod migrate_records_util;
+pub use migrate_records_util::*;
+
+pub mod get_receiver_id_util;
+pub use get_receiver_id_util::*;
+
+pub mod get_storage_name_util;
+pub use get_storage_name_util::*;
+
+pub mod get_record_storage_name_util;
+pub use get_record_storage_name_util::*;
+
+pub mod find_receiver_conflict_util;
+pub use find_receiver_conflict_util::*;
+
+pub mod store_receiver_with_resolution_util;
+pub use store_receiver_with_resolution_util::*;
+
+pub mod relabel_receiver_util;
+pub use relabel_receiver_util::*;
+
+pub mod create_backup_util;
+pub use create_backup_util::*;
+
+pub mod read_backup_util;
+pub use read_backup_util::*;
+
+pub mod diff_backup_util;
+pub use diff_backup_util::*;
+
+pub mod restore_backup_util;
+pub use restore_backup_util::*;
+
+pub mod store_sender_bundle_util;
+pub use store_sender_bundle_util::*;
+
+pub mod get_sender_bundle_util;
+pub use get_sender_bundle_util::*;
+
+pub mod remove_sender_bundle_util;
+pub use remove_sender_bundle_util::*;
+
+pub mod set_sender_share_status_util;
+pub use set_sender_share_status_util::*;
+
+pub mod store_inbox_entry_util;
+pub use store_inbox_entry_util::*;
+
+pub mod get_inbox_entry_util;
+pub use get_inbox_entry_util::*;
+
+pub mod remove_inbox_entry_util;
+pub use remove_inbox_entry_util::*;
@@ -0,0 +1,53 @@
+use base64::{engine::general_purpose::STANDARD, Engine};
+
+use wingedcap::sha_256;
+
+use crate::types::{BackupContent, BackupFile, RecordData};
+
+use crate::utils::{derive_vault_key, vault_decrypt};
+
+use super::super::constants::{BACKUP_FORMAT, BACKUP_VERSION};
+
+pub fn read_backup(backup_json: &str, passphrase: Option<&str>) -> Result<BackupContent, String> {
+    let backup: BackupFile =
+        serde_json::from_str(backup_json).map_err(|_| "not a backup file".to_string())?;
+
+    if backup.format != BACKUP_FORMAT {
+        return Err(format!("unknown backup format \"{}\"", backup.format));
+    }
+
+    if backup.version > BACKUP_VERSION {
+        return Err(format!(
+            "backup version {} is newer than this app supports ({})",
+            backup.version, BACKUP_VERSION
+        ));
+    }
+
+    let content_json = match backup.data {
+        RecordData::Plain(content) => serde_json::to_string(&content).map_err(|e| e.to_string())?,
+
+        RecordData::Encrypted(encrypted) => {
+            let passphrase = passphrase
+                .filter(|passphrase| !passphrase.is_empty())
+                .ok_or("this backup is protected by a passphrase")?;
+
+            let salt = STANDARD
+                .decode(backup.salt.ok_or("encrypted backup has no salt")?)
+                .map_err(|e| e.to_string())?;
+
+            let key = derive_vault_key(
+                passphrase,
+                &salt,
+                &backup.kdf.ok_or("encrypted backup has no key derivation")?,
+            )?;
+
+            vault_decrypt(&key, &encrypted).map_err(|_| "wrong passphrase".to_string())?
+        }
+    };
+
+    if sha_256(&content_json) != backup.checksum {
+        return Err("backup checksum mismatch, the file is corrupted".to_string());
+    }
+
+    serde_json::from_str(&content_json).map_err(|e| e.to_string())
+}
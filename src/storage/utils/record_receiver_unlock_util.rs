@@ -0,0 +1,18 @@
+use wingedcap::client::ReceiverStored;
+
+use super::{get_receiver_meta, store_receiver_meta};
+
+// keeps the first unlock time, returns it along with whether it was just recorded
+pub fn record_receiver_unlock(receiver: &ReceiverStored, at: i64) -> Result<(i64, bool), String> {
+    let mut meta = get_receiver_meta(receiver).unwrap_or_default();
+
+    if let Some(unlocked_at) = meta.unlocked_at {
+        return Ok((unlocked_at, false));
+    }
+
+    meta.unlocked_at = Some(at);
+
+    store_receiver_meta(receiver, meta)?;
+
+    Ok((at, true))
+}
@@ -0,0 +1,45 @@
+use wingedcap::client::SenderStored;
+
+use crate::constants::PING_HISTORY_MAX_LENGTH;
+
+use crate::types::{KeyHealth, KeyHealthStatus, PingRecord, SenderMeta};
+
+use super::{get_sender_meta, store_sender_meta};
+
+// keeps track of a ping in the sender meta, creating it for senders that have none
+pub fn record_sender_ping(
+    sender: &SenderStored,
+    keys_health: &[KeyHealth],
+    at: i64,
+) -> Result<SenderMeta, String> {
+    let mut meta = get_sender_meta(sender).unwrap_or_default();
+
+    meta.last_pings.resize(keys_health.len(), None);
+
+    // only a key that answered as still locked has had its timelock reset by the ping
+    for (last_ping, health) in meta.last_pings.iter_mut().zip(keys_health) {
+        if health.status == KeyHealthStatus::Locked {
+            *last_ping = Some(at);
+        }
+    }
+
+    meta.ping_history.push(PingRecord {
+        at,
+        reachable_keys: keys_health
+            .iter()
+            .filter(|health| health.is_reachable())
+            .count() as u64,
+        total_keys: keys_health.len() as u64,
+    });
+
+    let overflow = meta
+        .ping_history
+        .len()
+        .saturating_sub(PING_HISTORY_MAX_LENGTH);
+
+    meta.ping_history.drain(..overflow);
+
+    store_sender_meta(sender, meta.clone())?;
+
+    Ok(meta)
+}
@@ -0,0 +1,31 @@
+use cross_storage::storage_del;
+
+use wingedcap::client::ReceiverStored;
+
+use super::super::constants::RECEIVER_STORAGE_NAME_PREFIX;
+
+use super::{get_receiver_id, get_storage_name, store_receiver};
+
+// stores the receiver under its new label, moving the record if it was kept
+// under another key (e.g. a label-based one that could not be migrated yet)
+pub fn relabel_receiver(
+    storage_id: &str,
+    receiver: ReceiverStored,
+    label: String,
+) -> Result<(), String> {
+    let relabeled_receiver = ReceiverStored { label, ..receiver };
+
+    let storage_name = format!(
+        "{}_{}",
+        RECEIVER_STORAGE_NAME_PREFIX,
+        get_receiver_id(&relabeled_receiver)?
+    );
+
+    store_receiver(relabeled_receiver)?;
+
+    if get_storage_name(storage_id) != storage_name {
+        storage_del(storage_id)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,15 @@
+use wingedcap::client::ReceiverStored;
+
+use cross_storage::storage_del;
+
+use super::super::constants::RECEIVER_META_STORAGE_NAME_PREFIX;
+
+use super::get_receiver_id;
+
+pub fn remove_receiver_meta(receiver: &ReceiverStored) -> Result<(), String> {
+    let storage_id = get_receiver_id(receiver)?;
+
+    let storage_id = format!("{}_{}", RECEIVER_META_STORAGE_NAME_PREFIX, storage_id);
+
+    storage_del(&storage_id)
+}
@@ -0,0 +1,15 @@
+use wingedcap::client::SenderStored;
+
+use cross_storage::storage_del;
+
+use super::super::constants::SENDER_BUNDLE_STORAGE_NAME_PREFIX;
+
+use super::get_sender_id;
+
+pub fn remove_sender_bundle(sender: &SenderStored) -> Result<(), String> {
+    let storage_id = get_sender_id(sender)?;
+
+    let storage_id = format!("{}_{}", SENDER_BUNDLE_STORAGE_NAME_PREFIX, storage_id);
+
+    storage_del(&storage_id)
+}
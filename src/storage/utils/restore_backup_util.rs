@@ -0,0 +1,87 @@
+use crate::types::{BackupContent, BackupReceiver, BackupRecordStatus, ConflictResolution};
+
+use super::{
+    diff_backup, get_inbox_entry, get_receiver_meta, store_inbox_entry, store_receiver,
+    store_receiver_meta, store_receiver_with_resolution, store_sender, store_sender_bundle,
+    store_sender_meta, store_settings,
+};
+
+// writes the new records of the backup, and the conflicting ones over the stored ones when asked,
+// returning how many were written; a conflicting receiver only ever holds the same secret,
+// so overwriting it just gives it the backed up label
+pub fn restore_backup(
+    content: &BackupContent,
+    overwrite_conflicts: bool,
+    restore_settings: bool,
+) -> Result<usize, String> {
+    let diff = diff_backup(content)?;
+
+    let mut restored = 0;
+
+    for (backup_sender, status) in diff.senders {
+        let should_store = match status {
+            BackupRecordStatus::New => true,
+            BackupRecordStatus::Identical => false,
+            BackupRecordStatus::Conflicting => overwrite_conflicts,
+        };
+
+        if !should_store {
+            continue;
+        }
+
+        if let Some(meta) = backup_sender.meta {
+            store_sender_meta(&backup_sender.sender, meta)?;
+        }
+
+        if let Some(bundle) = backup_sender.bundle {
+            store_sender_bundle(&backup_sender.sender, bundle)?;
+        }
+
+        store_sender(backup_sender.sender)?;
+
+        restored += 1;
+    }
+
+    for (backup_receiver, status) in diff.receivers {
+        let BackupReceiver {
+            receiver,
+            meta,
+            inbox_entry,
+        } = backup_receiver;
+
+        let resolution = match status {
+            BackupRecordStatus::New => None,
+            BackupRecordStatus::Conflicting if overwrite_conflicts => {
+                Some(ConflictResolution::Replace)
+            }
+            // the stored one is kept as it is, nothing gets written
+            BackupRecordStatus::Identical | BackupRecordStatus::Conflicting => continue,
+        };
+
+        // the unlock record and saved message on this device are never overwritten
+        if let Some(meta) = meta {
+            if get_receiver_meta(&receiver).is_err() {
+                store_receiver_meta(&receiver, meta)?;
+            }
+        }
+
+        if let Some(inbox_entry) = inbox_entry {
+            if get_inbox_entry(&receiver)?.is_none() {
+                store_inbox_entry(&receiver, inbox_entry)?;
+            }
+        }
+
+        match resolution {
+            Some(resolution) => store_receiver_with_resolution(receiver, resolution)?,
+            None => store_receiver(receiver)?,
+        }
+
+        restored += 1;
+    }
+
+    if restore_settings {
+        store_settings(content.settings.clone())?;
+    }
+
+    Ok(restored)
+}
@@ -0,0 +1,20 @@
+use wingedcap::client::SenderStored;
+
+use crate::types::{SenderBundle, ShareStatus};
+
+use super::{get_sender_bundle, store_sender_bundle};
+
+pub fn set_sender_share_status(
+    sender: &SenderStored,
+    share_status: ShareStatus,
+) -> Result<(), String> {
+    let bundle = get_sender_bundle(sender)?.ok_or("no receiver data stored for this sender")?;
+
+    store_sender_bundle(
+        sender,
+        SenderBundle {
+            share_status,
+            ..bundle
+        },
+    )
+}
@@ -0,0 +1,16 @@
+use wingedcap::client::ReceiverStored;
+
+use crate::types::InboxEntry;
+
+use super::super::constants::INBOX_STORAGE_NAME_PREFIX;
+
+use super::{get_receiver_id, store_record};
+
+// stored like senders and receivers, so encrypted when the vault is enabled
+pub fn store_inbox_entry(receiver: &ReceiverStored, entry: InboxEntry) -> Result<(), String> {
+    let storage_id = get_receiver_id(receiver)?;
+
+    let storage_id = format!("{}_{}", INBOX_STORAGE_NAME_PREFIX, storage_id);
+
+    store_record(&storage_id, entry)
+}
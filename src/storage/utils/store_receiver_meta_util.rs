@@ -0,0 +1,17 @@
+use wingedcap::client::ReceiverStored;
+
+use cross_storage::storage_set_object;
+
+use crate::types::ReceiverMeta;
+
+use super::super::constants::RECEIVER_META_STORAGE_NAME_PREFIX;
+
+use super::get_receiver_id;
+
+pub fn store_receiver_meta(receiver: &ReceiverStored, meta: ReceiverMeta) -> Result<(), String> {
+    let storage_id = get_receiver_id(receiver)?;
+
+    let storage_id = format!("{}_{}", RECEIVER_META_STORAGE_NAME_PREFIX, storage_id);
+
+    storage_set_object(&storage_id, meta)
+}
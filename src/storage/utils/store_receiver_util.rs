@@ -1,11 +1,13 @@
 use wingedcap::client::ReceiverStored;
 
-use cross_storage::storage_set_object;
-
 use super::super::constants::RECEIVER_STORAGE_NAME_PREFIX;
 
+use super::{get_receiver_id, store_record};
+
 pub fn store_receiver(receiver: ReceiverStored) -> Result<(), String> {
-    let storage_id = format!("{}_{}", RECEIVER_STORAGE_NAME_PREFIX, receiver.label);
+    let storage_id = get_receiver_id(&receiver)?;
+
+    let storage_id = format!("{}_{}", RECEIVER_STORAGE_NAME_PREFIX, storage_id);
 
-    storage_set_object(&storage_id, receiver)
+    store_record(&storage_id, receiver)
 }
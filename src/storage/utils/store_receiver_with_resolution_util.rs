@@ -0,0 +1,37 @@
+use wingedcap::client::ReceiverStored;
+
+use crate::types::ConflictResolution;
+
+use super::{find_receiver_conflict, get_receiver_id, relabel_receiver, store_receiver};
+
+pub fn store_receiver_with_resolution(
+    receiver: ReceiverStored,
+    resolution: ConflictResolution,
+) -> Result<(), String> {
+    let conflict = find_receiver_conflict(&receiver)?;
+
+    match resolution {
+        ConflictResolution::Merge => match conflict {
+            // the very same secret is already stored, keep it as it is
+            Some((_, existing)) if get_receiver_id(&existing)? == get_receiver_id(&receiver)? => {
+                Ok(())
+            }
+
+            _ => store_receiver(receiver),
+        },
+
+        ConflictResolution::Rename(label) => store_receiver(ReceiverStored { label, ..receiver }),
+
+        // the very same secret only takes the new label, its unlock record and saved message stay;
+        // one that merely shares the label is kept alongside
+        ConflictResolution::Replace => match conflict {
+            Some((storage_id, existing))
+                if get_receiver_id(&existing)? == get_receiver_id(&receiver)? =>
+            {
+                relabel_receiver(&storage_id, existing, receiver.label)
+            }
+
+            _ => store_receiver(receiver),
+        },
+    }
+}
@@ -0,0 +1,32 @@
+use serde::Serialize;
+
+use cross_storage::storage_set_object;
+
+use crate::types::{RecordData, RecordEnvelope};
+
+use crate::utils::vault_encrypt;
+
+use super::super::constants::RECORD_VERSION;
+
+use super::{get_vault_key, is_vault_enabled};
+
+// stores a sender or receiver in a versioned envelope, encrypted when the vault is enabled
+pub fn store_record<T: Serialize>(storage_id: &str, record: T) -> Result<(), String> {
+    let record_json = serde_json::to_value(&record).map_err(|e| e.to_string())?;
+
+    let data = if is_vault_enabled() {
+        let key = get_vault_key().ok_or("vault is locked")?;
+
+        RecordData::Encrypted(vault_encrypt(&key, &record_json.to_string())?)
+    } else {
+        RecordData::Plain(record_json)
+    };
+
+    storage_set_object(
+        storage_id,
+        RecordEnvelope {
+            version: RECORD_VERSION,
+            data,
+        },
+    )
+}
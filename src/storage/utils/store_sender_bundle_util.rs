@@ -0,0 +1,16 @@
+use wingedcap::client::SenderStored;
+
+use crate::types::SenderBundle;
+
+use super::super::constants::SENDER_BUNDLE_STORAGE_NAME_PREFIX;
+
+use super::{get_sender_id, store_record};
+
+// stored like senders and receivers, so encrypted when the vault is enabled
+pub fn store_sender_bundle(sender: &SenderStored, bundle: SenderBundle) -> Result<(), String> {
+    let storage_id = get_sender_id(sender)?;
+
+    let storage_id = format!("{}_{}", SENDER_BUNDLE_STORAGE_NAME_PREFIX, storage_id);
+
+    store_record(&storage_id, bundle)
+}
@@ -0,0 +1,17 @@
+use wingedcap::client::SenderStored;
+
+use cross_storage::storage_set_object;
+
+use crate::types::SenderMeta;
+
+use super::super::constants::SENDER_META_STORAGE_NAME_PREFIX;
+
+use super::get_sender_id;
+
+pub fn store_sender_meta(sender: &SenderStored, meta: SenderMeta) -> Result<(), String> {
+    let storage_id = get_sender_id(sender)?;
+
+    let storage_id = format!("{}_{}", SENDER_META_STORAGE_NAME_PREFIX, storage_id);
+
+    storage_set_object(&storage_id, meta)
+}
@@ -0,0 +1,9 @@
+use cross_storage::storage_set_object;
+
+use crate::types::Settings;
+
+use super::super::constants::SETTINGS_STORAGE_NAME;
+
+pub fn store_settings(settings: Settings) -> Result<(), String> {
+    storage_set_object(SETTINGS_STORAGE_NAME, settings)
+}
@@ -0,0 +1,24 @@
+use base64::{engine::general_purpose::STANDARD, Engine};
+
+use crate::constants::VAULT_CHECK_PLAINTEXT;
+
+use crate::utils::{derive_vault_key, vault_decrypt};
+
+use super::{get_vault_header, set_vault_key};
+
+pub fn unlock_vault(passphrase: &str) -> Result<(), String> {
+    let header = get_vault_header()?.ok_or("vault is not enabled")?;
+
+    let salt = STANDARD.decode(&header.salt).map_err(|e| e.to_string())?;
+
+    let key = derive_vault_key(passphrase, &salt, &header.kdf)?;
+
+    match vault_decrypt(&key, &header.check) {
+        Ok(check) if check == VAULT_CHECK_PLAINTEXT => {
+            set_vault_key(Some(key));
+            Ok(())
+        }
+
+        _ => Err("wrong passphrase".to_string()),
+    }
+}
@@ -0,0 +1,14 @@
+use std::sync::Mutex;
+
+// key of the unlocked vault, it never leaves memory
+static VAULT_KEY: Mutex<Option<[u8; 32]>> = Mutex::new(None);
+
+pub fn get_vault_key() -> Option<[u8; 32]> {
+    VAULT_KEY.lock().ok().and_then(|key| *key)
+}
+
+pub fn set_vault_key(key: Option<[u8; 32]>) {
+    if let Ok(mut vault_key) = VAULT_KEY.lock() {
+        *vault_key = key;
+    }
+}
@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+
+use super::{BackupReceiver, BackupSender, Settings};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct BackupContent {
+    pub senders: Vec<BackupSender>,
+    pub receivers: Vec<BackupReceiver>,
+    pub settings: Settings,
+}
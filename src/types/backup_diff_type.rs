@@ -0,0 +1,7 @@
+use super::{BackupReceiver, BackupRecordStatus, BackupSender};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct BackupDiff {
+    pub senders: Vec<(BackupSender, BackupRecordStatus)>,
+    pub receivers: Vec<(BackupReceiver, BackupRecordStatus)>,
+}
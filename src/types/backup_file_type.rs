@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+
+use super::{KdfParams, RecordData};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct BackupFile {
+    // always BACKUP_FORMAT, tells backups apart from single records
+    pub format: String,
+    pub version: u32,
+    // base64 salt of the passphrase, only for encrypted backups
+    pub salt: Option<String>,
+    // key derivation of the passphrase, only for encrypted backups
+    pub kdf: Option<KdfParams>,
+    // sha_256 of the BackupContent json, before encryption
+    pub checksum: String,
+    pub data: RecordData,
+}
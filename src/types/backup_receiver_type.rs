@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+
+use wingedcap::client::ReceiverStored;
+
+use super::{InboxEntry, ReceiverMeta};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct BackupReceiver {
+    pub receiver: ReceiverStored,
+    #[serde(default)]
+    pub meta: Option<ReceiverMeta>,
+    // decrypted messages saved on the device, so they survive the key servers forgetting the keys
+    #[serde(default)]
+    pub inbox_entry: Option<InboxEntry>,
+}
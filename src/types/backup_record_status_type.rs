@@ -0,0 +1,9 @@
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BackupRecordStatus {
+    // not stored yet
+    New,
+    // stored exactly as in the backup
+    Identical,
+    // the same secret stored under another label, or another secret under the same label
+    Conflicting,
+}
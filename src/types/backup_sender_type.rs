@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+
+use wingedcap::client::SenderStored;
+
+use super::{SenderBundle, SenderMeta};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct BackupSender {
+    pub sender: SenderStored,
+    pub meta: Option<SenderMeta>,
+    // missing from backups made before bundles were stored
+    #[serde(default)]
+    pub bundle: Option<SenderBundle>,
+}
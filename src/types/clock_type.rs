@@ -0,0 +1,13 @@
+use chrono::{DateTime, Utc};
+
+// source of the current time, swapped for a fixed one when checking time math
+pub trait Clock {
+    fn now(&self) -> DateTime<Utc>;
+}
+
+// a fixed point in time, e.g. the time of the last render
+impl Clock for DateTime<Utc> {
+    fn now(&self) -> DateTime<Utc> {
+        *self
+    }
+}
@@ -0,0 +1,10 @@
+#[derive(Clone, Debug, PartialEq)]
+pub enum ConflictResolution {
+    // keep both, or the stored one alone when it holds the very same secret
+    Merge,
+    // store the new one under another label
+    Rename(String),
+    // give the stored one holding the same secret the new label, keeping its unlock record
+    // and saved message, a stored one that only shares the label is kept alongside
+    Replace,
+}
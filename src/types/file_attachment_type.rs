@@ -0,0 +1,6 @@
+#[derive(Clone, Debug, PartialEq)]
+pub struct FileAttachment {
+    pub name: String,
+    pub mime: String,
+    pub bytes: Vec<u8>,
+}
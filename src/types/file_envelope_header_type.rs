@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct FileEnvelopeHeader {
+    pub name: String,
+    pub mime: String,
+    // of the original file, before compression
+    pub size: u64,
+    pub compressed: bool,
+}
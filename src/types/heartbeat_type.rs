@@ -0,0 +1,13 @@
+use wingedcap::client::{SenderState, SenderStored};
+
+use super::{KeyHealth, SenderMeta};
+
+// a sender kept pinged by the heartbeat scheduler, with the outcome of its last ping
+#[derive(Clone, PartialEq)]
+pub struct Heartbeat {
+    pub sender: SenderStored,
+    pub state: Option<SenderState>,
+    pub keys_health: Option<Vec<KeyHealth>>,
+    pub next_ping: Option<i64>,
+    pub meta: Option<SenderMeta>,
+}
@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+
+use super::Hub;
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct HubSettings {
+    pub hubs: Vec<Hub>,
+    pub default_hub: Option<String>,
+}
@@ -0,0 +1,8 @@
+use serde::{Deserialize, Serialize};
+
+// decrypted messages of an unlocked receiver, kept in case the key servers forget the keys
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct InboxEntry {
+    pub decrypted_messages: Vec<String>,
+    pub captured_at: i64,
+}
@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+
+use super::KdfAlgorithm;
+
+// stored next to every salt, so a passphrase keeps deriving the same key whatever
+// the defaults of the argon2 crate become
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct KdfParams {
+    pub algorithm: KdfAlgorithm,
+    pub version: u32,
+    // memory in KiB
+    pub m_cost: u32,
+    pub t_cost: u32,
+    pub p_cost: u32,
+}
@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase", tag = "state", content = "error")]
+pub enum KeyHealthStatus {
+    Locked,
+    Unlocked,
+    // the server could not be reached (or answered garbage), so the key state is unknown
+    Unreachable(String),
+}
@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+
+use wingedcap::client::Key;
+
+use super::KeyHealthStatus;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct KeyHealth {
+    pub key: Key,
+    pub status: KeyHealthStatus,
+    // milliseconds the server took to answer (or to fail)
+    pub latency: u64,
+}
+
+impl KeyHealth {
+    pub fn is_reachable(&self) -> bool {
+        !matches!(self.status, KeyHealthStatus::Unreachable(_))
+    }
+}
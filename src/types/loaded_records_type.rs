@@ -0,0 +1,8 @@
+use super::RecordFailure;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct LoadedRecords<T> {
+    pub records: Vec<(String, T)>,
+    // records that are stored but could not be read
+    pub failures: Vec<RecordFailure>,
+}
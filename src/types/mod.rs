@@ -1,4 +1,6 @@
+#[cfg(any(feature = "desktop", feature = "web", feature = "mobile"))]
 mod get_class;
+#[cfg(any(feature = "desktop", feature = "web", feature = "mobile"))]
 pub use get_class::GetClass;
 
 mod time_unit_type;
@@ -7,5 +9,129 @@ pub use time_unit_type::TimeUnit;
 mod time_type;
 pub use time_type::Time;
 
+mod clock_type;
+pub use clock_type::Clock;
+
+mod system_clock_type;
+pub use system_clock_type::SystemClock;
+
+#[cfg(any(feature = "desktop", feature = "web", feature = "mobile"))]
 mod attributes_type;
+#[cfg(any(feature = "desktop", feature = "web", feature = "mobile"))]
 pub use attributes_type::*;
+
+mod sender_meta_type;
+pub use sender_meta_type::SenderMeta;
+
+mod ping_record_type;
+pub use ping_record_type::PingRecord;
+
+mod receiver_meta_type;
+pub use receiver_meta_type::ReceiverMeta;
+
+mod receiver_poll_type;
+pub use receiver_poll_type::ReceiverPoll;
+
+mod hub_type;
+pub use hub_type::Hub;
+
+mod hub_settings_type;
+pub use hub_settings_type::HubSettings;
+
+mod theme_type;
+pub use theme_type::Theme;
+
+mod settings_type;
+pub use settings_type::Settings;
+
+mod set_key_outcome_type;
+pub use set_key_outcome_type::SetKeyOutcome;
+
+mod set_secret_options_type;
+pub use set_secret_options_type::SetSecretOptions;
+
+mod set_secret_error_type;
+pub use set_secret_error_type::SetSecretError;
+
+mod key_health_status_type;
+pub use key_health_status_type::KeyHealthStatus;
+
+mod key_health_type;
+pub use key_health_type::KeyHealth;
+
+mod heartbeat_type;
+pub use heartbeat_type::Heartbeat;
+
+mod kdf_algorithm_type;
+pub use kdf_algorithm_type::KdfAlgorithm;
+
+mod kdf_params_type;
+pub use kdf_params_type::KdfParams;
+
+mod vault_header_type;
+pub use vault_header_type::VaultHeader;
+
+#[cfg(any(feature = "desktop", feature = "web", feature = "mobile"))]
+mod vault_session_type;
+#[cfg(any(feature = "desktop", feature = "web", feature = "mobile"))]
+pub use vault_session_type::VaultSession;
+
+mod record_data_type;
+pub use record_data_type::RecordData;
+
+mod record_envelope_type;
+pub use record_envelope_type::RecordEnvelope;
+
+mod record_migration_type;
+pub use record_migration_type::RecordMigration;
+
+mod record_failure_type;
+pub use record_failure_type::RecordFailure;
+
+mod loaded_records_type;
+pub use loaded_records_type::LoadedRecords;
+
+mod stored_record_type;
+pub use stored_record_type::StoredRecord;
+
+mod conflict_resolution_type;
+pub use conflict_resolution_type::ConflictResolution;
+
+mod backup_sender_type;
+pub use backup_sender_type::BackupSender;
+
+mod backup_receiver_type;
+pub use backup_receiver_type::BackupReceiver;
+
+mod backup_content_type;
+pub use backup_content_type::BackupContent;
+
+mod backup_file_type;
+pub use backup_file_type::BackupFile;
+
+mod backup_record_status_type;
+pub use backup_record_status_type::BackupRecordStatus;
+
+mod backup_diff_type;
+pub use backup_diff_type::BackupDiff;
+
+mod share_status_type;
+pub use share_status_type::ShareStatus;
+
+mod sender_bundle_type;
+pub use sender_bundle_type::SenderBundle;
+
+mod inbox_entry_type;
+pub use inbox_entry_type::InboxEntry;
+
+mod secret_payload_type;
+pub use secret_payload_type::SecretPayload;
+
+mod file_envelope_header_type;
+pub use file_envelope_header_type::FileEnvelopeHeader;
+
+mod file_attachment_type;
+pub use file_attachment_type::FileAttachment;
+
+mod split_share_type;
+pub use split_share_type::SplitShare;
@@ -0,0 +1,8 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PingRecord {
+    pub at: i64,
+    pub reachable_keys: u64,
+    pub total_keys: u64,
+}
@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+
+// client-side sidecar of a receiver
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ReceiverMeta {
+    // first time the receiver was seen unlocked
+    #[serde(default)]
+    pub unlocked_at: Option<i64>,
+}
@@ -0,0 +1,11 @@
+use wingedcap::client::ReceiverState;
+
+use super::KeyHealth;
+
+// outcome of the last successful poll of a receiver
+#[derive(Clone, Debug, PartialEq)]
+pub struct ReceiverPoll {
+    pub state: ReceiverState,
+    pub keys_health: Vec<KeyHealth>,
+    pub unlocked_at: Option<i64>,
+}
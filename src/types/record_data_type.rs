@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+
+use serde_json::Value;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RecordData {
+    Plain(Value),
+    // base64 of the nonce followed by the encrypted record json
+    Encrypted(String),
+}
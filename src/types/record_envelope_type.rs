@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+
+use super::RecordData;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RecordEnvelope {
+    pub version: u32,
+    pub data: RecordData,
+}
@@ -0,0 +1,8 @@
+use serde_json::Value;
+
+#[derive(Clone, Copy)]
+pub struct RecordMigration {
+    pub from_version: u32,
+    // receives the storage prefix of the record (sender or receiver) and its json
+    pub migrate: fn(&str, Value) -> Result<Value, String>,
+}
@@ -0,0 +1,13 @@
+use serde_json::Value;
+
+use super::FileAttachment;
+
+// how a decrypted message should be rendered
+#[derive(Clone, Debug, PartialEq)]
+pub enum SecretPayload {
+    Text(String),
+    Json(Value),
+    File(FileAttachment),
+    // needs the recipient passphrase before it can be detected any further
+    PassphraseProtected(String),
+}
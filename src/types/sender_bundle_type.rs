@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+use wingedcap::client::Receiver;
+
+use super::ShareStatus;
+
+// the receiver data of a sender, kept to share it again later on
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SenderBundle {
+    pub receiver: Receiver,
+    pub share_status: ShareStatus,
+}
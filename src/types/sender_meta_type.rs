@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+
+use super::{PingRecord, Time};
+
+// client-side sidecar of a sender, missing fields come from older versions or imported senders
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct SenderMeta {
+    #[serde(default)]
+    pub timelock: Option<Time>,
+    #[serde(default)]
+    pub created_at: Option<i64>,
+    // fewest keys able to unlock the secret together
+    #[serde(default)]
+    pub required_keys: Option<u64>,
+    // last successful ping of each key, by key index
+    #[serde(default)]
+    pub last_pings: Vec<Option<i64>>,
+    // oldest first, capped to PING_HISTORY_MAX_LENGTH
+    #[serde(default)]
+    pub ping_history: Vec<PingRecord>,
+}
+
+impl SenderMeta {
+    pub fn last_ping(&self) -> Option<i64> {
+        self.last_pings.iter().flatten().max().copied()
+    }
+}
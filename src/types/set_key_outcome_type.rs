@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+
+use wingedcap::client::Server;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SetKeyOutcome {
+    pub server: Server,
+    pub attempts: u32,
+    pub error: Option<String>,
+}
@@ -0,0 +1,32 @@
+use super::SetKeyOutcome;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SetSecretError {
+    pub message: String,
+    // one per server, in the order they were given
+    pub outcomes: Vec<SetKeyOutcome>,
+}
+
+impl std::fmt::Display for SetSecretError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)?;
+
+        for outcome in self.outcomes.iter() {
+            if let Some(error) = &outcome.error {
+                write!(
+                    f,
+                    "\n{} failed after {} attempt(s): {}",
+                    outcome.server.host, outcome.attempts, error
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl From<SetSecretError> for String {
+    fn from(error: SetSecretError) -> Self {
+        error.to_string()
+    }
+}
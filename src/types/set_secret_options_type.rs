@@ -0,0 +1,24 @@
+use serde::{Deserialize, Serialize};
+
+use crate::constants::{DEFAULT_SET_KEY_BACKOFF, DEFAULT_SET_KEY_RETRIES};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SetSecretOptions {
+    // extra attempts per server after the first one fails
+    pub retries: u32,
+    // milliseconds before the first retry, doubled on each following one
+    pub backoff: u32,
+    // keep the keys that were set when the others still meet the required keys
+    pub allow_partial: bool,
+}
+
+impl Default for SetSecretOptions {
+    fn default() -> Self {
+        Self {
+            retries: DEFAULT_SET_KEY_RETRIES,
+            backoff: DEFAULT_SET_KEY_BACKOFF,
+            allow_partial: false,
+        }
+    }
+}
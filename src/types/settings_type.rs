@@ -0,0 +1,49 @@
+use serde::{Deserialize, Serialize};
+
+use crate::constants::{
+    DEFAULT_CLIPBOARD_CLEAR_DELAY, DEFAULT_HEARTBEAT_FRACTION, DEFAULT_RECEIVER_POLL_INTERVAL,
+    DEFAULT_UNLOCK_WARNINGS, DEFAULT_VAULT_AUTO_LOCK,
+};
+
+use super::{HubSettings, SetSecretOptions, Theme, Time, TimeUnit};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    pub default_timelock: Time,
+    // share of the keys required to decrypt, rounded up to at least one key
+    pub default_required_keys_ratio: f64,
+    // share of a sender's timelock to wait between two pings
+    pub heartbeat_fraction: f64,
+    // seconds before a sender's projected unlock to warn at
+    pub unlock_warnings: Vec<u64>,
+    // seconds between two checks of every stored receiver, 0 disables it
+    pub receiver_poll_interval: u64,
+    pub key_creation: SetSecretOptions,
+    pub hubs: HubSettings,
+    // seconds before copied secrets are wiped from the clipboard, 0 disables it
+    pub clipboard_clear_delay: u64,
+    // seconds without interaction before the vault locks, 0 disables it
+    pub vault_auto_lock: u64,
+    pub theme: Theme,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            default_timelock: Time {
+                magnitude: 10,
+                unit: TimeUnit::Second,
+            },
+            default_required_keys_ratio: 0.0,
+            heartbeat_fraction: DEFAULT_HEARTBEAT_FRACTION,
+            unlock_warnings: DEFAULT_UNLOCK_WARNINGS.to_vec(),
+            receiver_poll_interval: DEFAULT_RECEIVER_POLL_INTERVAL,
+            key_creation: SetSecretOptions::default(),
+            hubs: HubSettings::default(),
+            clipboard_clear_delay: DEFAULT_CLIPBOARD_CLEAR_DELAY,
+            vault_auto_lock: DEFAULT_VAULT_AUTO_LOCK,
+            theme: Theme::default(),
+        }
+    }
+}
@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ShareStatus {
+    #[default]
+    NotShared,
+    // copied or shown as a QR code, the recipient may not have it yet
+    Copied,
+    // the user confirmed the recipient stored it
+    Confirmed,
+}
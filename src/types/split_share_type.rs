@@ -0,0 +1,9 @@
+#[derive(Clone, Debug, PartialEq)]
+pub struct SplitShare {
+    // tells shares of the same split apart from shares of another one
+    pub split_id: String,
+    pub threshold: u8,
+    pub index: u8,
+    pub total: u8,
+    pub data: Vec<u8>,
+}
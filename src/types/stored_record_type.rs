@@ -0,0 +1,8 @@
+use serde_json::Value;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct StoredRecord {
+    pub storage_id: String,
+    // the raw json, or why it couldn't be read
+    pub json: Result<Value, String>,
+}
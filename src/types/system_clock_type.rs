@@ -0,0 +1,12 @@
+use chrono::{DateTime, Utc};
+
+use super::Clock;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
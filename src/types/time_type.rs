@@ -1,6 +1,8 @@
+use serde::{Deserialize, Serialize};
+
 use super::TimeUnit;
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Time {
     pub magnitude: u64,
     pub unit: TimeUnit,
@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+use super::KdfParams;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct VaultHeader {
+    // base64 salt fed to the key derivation
+    pub salt: String,
+    pub kdf: KdfParams,
+    // VAULT_CHECK_PLAINTEXT encrypted with the derived key
+    pub check: String,
+}
@@ -0,0 +1,8 @@
+use dioxus::prelude::*;
+
+#[derive(Clone, Copy, PartialEq)]
+pub struct VaultSession {
+    pub is_locked: Signal<bool>,
+    // unix time of the last click or key press, used to auto-lock the vault
+    pub last_activity: Signal<i64>,
+}
@@ -13,5 +13,5 @@ pub mod separator;
 pub mod spinner;
 // pub mod table;
 pub mod tabs;
-// pub mod textarea;
+pub mod textarea;
 pub mod toast;
@@ -24,8 +24,9 @@ pub trait ToastRenderer {
     // fn duration_in_ms(&mut self, duration: u32) -> &mut Self;
     // fn animation(&mut self, animation: Animation) -> &mut Self;
     // fn is_closable(&mut self, is_closable: bool) -> &mut Self;
-    // fn success(&mut self, description: impl ToString);
+    fn success(&mut self, description: impl ToString);
     fn error(&mut self, description: impl ToString);
+    fn warning(&mut self, description: Element, duration: u32);
     // fn loading(&mut self, description: impl ToString);
 }
 
@@ -66,17 +67,17 @@ impl ToastRenderer for Signal<ToasterState> {
     //     self
     // }
 
-    // /// Build a toast with success background color and title "Success"
-    // /// The string passed as argument will be the description of the Toast
-    // fn success(&mut self, description: impl ToString) {
-    //     let toast = Toast::default()
-    //         .title(String::from("Success"))
-    //         .color(Color::Success)
-    //         .description(rsx! {
-    //             p { "{description.to_string()}" }
-    //         });
-    //     self.write().toasts.push(toast);
-    // }
+    /// Build a toast with success background color and title "Success"
+    /// The string passed as argument will be the description of the Toast
+    fn success(&mut self, description: impl ToString) {
+        let toast = Toast::default()
+            .title(String::from("Success"))
+            .color(Color::Success)
+            .description(rsx! {
+                p { "{description.to_string()}" }
+            });
+        self.write().toasts.push(toast);
+    }
 
     /// Build a toast with destructive background color and title "Error"
     /// The string passed as argument will be the description of the Toast
@@ -90,6 +91,16 @@ impl ToastRenderer for Signal<ToasterState> {
         self.write().toasts.push(toast);
     }
 
+    /// Build a toast with title "Warning" kept open for the given duration
+    /// The description may hold actions, e.g. buttons
+    fn warning(&mut self, description: Element, duration: u32) {
+        let toast = Toast::default()
+            .title(String::from("Warning"))
+            .duration_in_ms(duration)
+            .description(description);
+        self.write().toasts.push(toast);
+    }
+
     // /// Build a toast with primary background color and title "Loading"
     // /// The string passed as argument will be the description of the Toast
     // fn loading(&mut self, description: impl ToString) {
@@ -187,10 +198,10 @@ impl Toast {
     //     self
     // }
 
-    // pub fn duration_in_ms(mut self, duration: u32) -> Self {
-    //     self.duration_in_ms = duration;
-    //     self
-    // }
+    pub fn duration_in_ms(mut self, duration: u32) -> Self {
+        self.duration_in_ms = duration;
+        self
+    }
 
     // pub fn is_closable(mut self, is_closable: bool) -> Self {
     //     self.is_closable = is_closable;
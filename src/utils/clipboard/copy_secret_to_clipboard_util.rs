@@ -0,0 +1,28 @@
+use dioxus::prelude::spawn;
+
+use cross_clipboard::{copy_to_clipboard, paste_from_clipboard};
+
+use crate::storage::get_settings;
+
+use crate::utils::wait;
+
+pub fn copy_secret_to_clipboard(text: &str) -> Result<(), String> {
+    copy_to_clipboard(text)?;
+
+    let clear_delay = get_settings().unwrap_or_default().clipboard_clear_delay;
+
+    if clear_delay > 0 {
+        let copied_text = text.to_string();
+
+        spawn(async move {
+            wait((clear_delay * 1000).min(u32::MAX as u64) as u32).await;
+
+            // leave the clipboard alone if something else was copied meanwhile
+            if paste_from_clipboard().await.ok() == Some(copied_text) {
+                let _ = copy_to_clipboard("");
+            }
+        });
+    }
+
+    Ok(())
+}
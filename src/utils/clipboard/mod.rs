@@ -0,0 +1,2 @@
+pub mod copy_secret_to_clipboard_util;
+pub use copy_secret_to_clipboard_util::*;
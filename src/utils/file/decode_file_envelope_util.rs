@@ -0,0 +1,127 @@
+use std::{io::Read, path::Path};
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+
+use flate2::read::DeflateDecoder;
+
+use crate::constants::{FILE_ENVELOPE_PREFIX, MAX_FILE_ATTACHMENT_SIZE};
+
+use crate::types::{FileAttachment, FileEnvelopeHeader};
+
+pub fn decode_file_envelope(message: &str) -> Result<FileAttachment, String> {
+    let (header_line, content) = message.split_once('\n').ok_or("missing file content")?;
+
+    let header_json = header_line
+        .strip_prefix(FILE_ENVELOPE_PREFIX)
+        .ok_or("not a file envelope")?;
+
+    let FileEnvelopeHeader {
+        name,
+        mime,
+        size,
+        compressed,
+    } = serde_json::from_str(header_json.trim()).map_err(|e| e.to_string())?;
+
+    // the header comes from the sender, a huge size would let a deflate bomb inflate unbounded
+    if size > MAX_FILE_ATTACHMENT_SIZE {
+        return Err(format!(
+            "file of {} bytes is larger than the {} bytes allowed",
+            size, MAX_FILE_ATTACHMENT_SIZE
+        ));
+    }
+
+    let content = STANDARD.decode(content.trim()).map_err(|e| e.to_string())?;
+
+    let bytes = if compressed {
+        let mut bytes = vec![];
+
+        // reads one byte past the announced size to catch a mismatch without inflating everything
+        DeflateDecoder::new(content.as_slice())
+            .take(size.saturating_add(1))
+            .read_to_end(&mut bytes)
+            .map_err(|e| e.to_string())?;
+
+        bytes
+    } else {
+        content
+    };
+
+    if bytes.len() as u64 != size {
+        return Err("file size does not match its header".to_string());
+    }
+
+    // the name comes from the sender, only its last component is kept
+    let name = Path::new(&name)
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or("file".to_string());
+
+    Ok(FileAttachment { name, mime, bytes })
+}
+
+#[cfg(test)]
+mod tests {
+    use base64::{engine::general_purpose::STANDARD, Engine};
+
+    use crate::constants::{FILE_ENVELOPE_PREFIX, MAX_FILE_ATTACHMENT_SIZE};
+
+    use crate::types::{FileAttachment, FileEnvelopeHeader};
+
+    use crate::utils::encode_file_envelope;
+
+    use super::decode_file_envelope;
+
+    fn envelope(size: u64, compressed: bool, content: &[u8]) -> String {
+        let header = FileEnvelopeHeader {
+            name: "notes.txt".to_string(),
+            mime: "text/plain".to_string(),
+            size,
+            compressed,
+        };
+
+        format!(
+            "{} {}\n{}",
+            FILE_ENVELOPE_PREFIX,
+            serde_json::to_string(&header).unwrap(),
+            STANDARD.encode(content)
+        )
+    }
+
+    #[test]
+    fn round_trips() {
+        let attachment = FileAttachment {
+            name: "notes.txt".to_string(),
+            mime: "text/plain".to_string(),
+            bytes: "a".repeat(1000).into_bytes(),
+        };
+
+        let message = encode_file_envelope(&attachment).unwrap();
+
+        assert_eq!(decode_file_envelope(&message), Ok(attachment));
+    }
+
+    #[test]
+    fn rejects_an_oversized_header() {
+        assert!(decode_file_envelope(&envelope(u64::MAX, true, b"")).is_err());
+        assert!(decode_file_envelope(&envelope(MAX_FILE_ATTACHMENT_SIZE + 1, false, b"")).is_err());
+    }
+
+    #[test]
+    fn rejects_a_mismatched_size() {
+        let attachment = FileAttachment {
+            name: "notes.txt".to_string(),
+            mime: "text/plain".to_string(),
+            bytes: "a".repeat(1000).into_bytes(),
+        };
+
+        let message = encode_file_envelope(&attachment).unwrap();
+
+        let (_, content) = message.split_once('\n').unwrap();
+
+        let compressed = STANDARD.decode(content).unwrap();
+
+        assert!(decode_file_envelope(&envelope(999, true, &compressed)).is_err());
+        assert!(decode_file_envelope(&envelope(1001, true, &compressed)).is_err());
+        assert!(decode_file_envelope(&envelope(4, false, b"abc")).is_err());
+    }
+}
@@ -0,0 +1,44 @@
+use std::io::Write;
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+
+use flate2::{write::DeflateEncoder, Compression};
+
+use crate::constants::FILE_ENVELOPE_PREFIX;
+
+use crate::types::{FileAttachment, FileEnvelopeHeader};
+
+// turns a file into a message that goes through the usual secret creation
+pub fn encode_file_envelope(
+    FileAttachment { name, mime, bytes }: &FileAttachment,
+) -> Result<String, String> {
+    let mut encoder = DeflateEncoder::new(vec![], Compression::best());
+
+    let compressed_bytes = encoder
+        .write_all(bytes)
+        .and_then(|_| encoder.finish())
+        .map_err(|e| e.to_string())?;
+
+    // images and archives are usually compressed already, they are kept as is
+    let (content, compressed) = if compressed_bytes.len() < bytes.len() {
+        (compressed_bytes, true)
+    } else {
+        (bytes.clone(), false)
+    };
+
+    let header = FileEnvelopeHeader {
+        name: name.clone(),
+        mime: mime.clone(),
+        size: bytes.len() as u64,
+        compressed,
+    };
+
+    let header_json = serde_json::to_string(&header).map_err(|e| e.to_string())?;
+
+    Ok(format!(
+        "{} {}\n{}",
+        FILE_ENVELOPE_PREFIX,
+        header_json,
+        STANDARD.encode(content)
+    ))
+}
@@ -0,0 +1,28 @@
+use std::path::Path;
+
+use crate::constants::DEFAULT_FILE_MIME_TYPE;
+
+// for files picked without a content type, e.g. from the cli
+pub fn guess_mime_type(file_name: &str) -> String {
+    let extension = Path::new(file_name)
+        .extension()
+        .map(|extension| extension.to_string_lossy().to_lowercase())
+        .unwrap_or_default();
+
+    let mime = match extension.as_str() {
+        "txt" => "text/plain",
+        "md" => "text/markdown",
+        "csv" => "text/csv",
+        "json" => "application/json",
+        "pdf" => "application/pdf",
+        "zip" => "application/zip",
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "webp" => "image/webp",
+        "asc" | "gpg" | "pgp" => "application/pgp-encrypted",
+        _ => DEFAULT_FILE_MIME_TYPE,
+    };
+
+    mime.to_string()
+}
@@ -0,0 +1,13 @@
+#[cfg(any(feature = "desktop", feature = "web", feature = "mobile"))]
+pub mod save_file_util;
+#[cfg(any(feature = "desktop", feature = "web", feature = "mobile"))]
+pub use save_file_util::*;
+
+pub mod encode_file_envelope_util;
+pub use encode_file_envelope_util::*;
+
+pub mod decode_file_envelope_util;
+pub use decode_file_envelope_util::*;
+
+pub mod guess_mime_type_util;
+pub use guess_mime_type_util::*;
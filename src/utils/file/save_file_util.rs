@@ -0,0 +1,49 @@
+#[cfg(not(feature = "desktop"))]
+use base64::{engine::general_purpose::STANDARD, Engine};
+
+#[cfg(not(feature = "desktop"))]
+use dioxus::prelude::document;
+
+// asks where to save on desktop, downloads it through the browser elsewhere
+pub async fn save_file(
+    file_name: &str,
+    content: impl AsRef<[u8]>,
+    mime_type: &str,
+) -> Result<(), String> {
+    #[cfg(feature = "desktop")]
+    {
+        let _ = mime_type;
+
+        let file = rfd::AsyncFileDialog::new()
+            .set_file_name(file_name)
+            .save_file()
+            .await
+            .ok_or("no file selected")?;
+
+        std::fs::write(file.path(), content).map_err(|e| e.to_string())
+    }
+
+    #[cfg(not(feature = "desktop"))]
+    {
+        let eval = document::eval(
+            r#"
+            const [fileName, content, mimeType] = await dioxus.recv();
+
+            // sent as base64 so that binary files survive the trip
+            const bytes = Uint8Array.from(atob(content), (char) => char.charCodeAt(0));
+
+            const url = URL.createObjectURL(new Blob([bytes], { type: mimeType }));
+
+            const link = document.createElement("a");
+            link.href = url;
+            link.download = fileName;
+            link.click();
+
+            URL.revokeObjectURL(url);
+            "#,
+        );
+
+        eval.send((file_name, STANDARD.encode(content), mime_type))
+            .map_err(|e| e.to_string())
+    }
+}
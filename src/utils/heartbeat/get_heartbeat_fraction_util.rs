@@ -0,0 +1,11 @@
+use crate::constants::DEFAULT_HEARTBEAT_FRACTION;
+
+use crate::storage::get_settings;
+
+pub fn get_heartbeat_fraction() -> f64 {
+    get_settings()
+        .map(|settings| settings.heartbeat_fraction)
+        .ok()
+        .filter(|fraction| *fraction > 0.0 && *fraction <= 1.0)
+        .unwrap_or(DEFAULT_HEARTBEAT_FRACTION)
+}
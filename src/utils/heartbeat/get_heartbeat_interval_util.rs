@@ -0,0 +1,7 @@
+use crate::constants::MIN_HEARTBEAT_INTERVAL;
+
+pub fn get_heartbeat_interval(timelock: u64, fraction: f64) -> u64 {
+    let interval = (timelock as f64 * fraction).floor() as u64;
+
+    interval.max(MIN_HEARTBEAT_INTERVAL)
+}
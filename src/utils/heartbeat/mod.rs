@@ -0,0 +1,28 @@
+pub mod get_heartbeat_fraction_util;
+pub use get_heartbeat_fraction_util::*;
+
+pub mod get_heartbeat_interval_util;
+pub use get_heartbeat_interval_util::*;
+
+pub mod ping_heartbeat_util;
+pub use ping_heartbeat_util::*;
+
+#[cfg(any(feature = "desktop", feature = "web", feature = "mobile"))]
+pub mod use_unlock_warnings_util;
+#[cfg(any(feature = "desktop", feature = "web", feature = "mobile"))]
+pub use use_unlock_warnings_util::*;
+
+#[cfg(any(feature = "desktop", feature = "web", feature = "mobile"))]
+pub mod request_ping_util;
+#[cfg(any(feature = "desktop", feature = "web", feature = "mobile"))]
+pub use request_ping_util::*;
+
+#[cfg(any(feature = "desktop", feature = "web", feature = "mobile"))]
+pub mod use_heartbeat_scheduler_util;
+#[cfg(any(feature = "desktop", feature = "web", feature = "mobile"))]
+pub use use_heartbeat_scheduler_util::*;
+
+#[cfg(any(feature = "desktop", feature = "web", feature = "mobile"))]
+pub mod use_receiver_poller_util;
+#[cfg(any(feature = "desktop", feature = "web", feature = "mobile"))]
+pub use use_receiver_poller_util::*;
@@ -0,0 +1,37 @@
+use wingedcap::{
+    client::{Key, Sender, SenderStored},
+    get_current_unix_time,
+};
+
+use crate::storage::record_sender_ping;
+
+use crate::types::Heartbeat;
+
+use crate::utils::ping_secret;
+
+// pings a sender once and feeds the outcome to its heartbeat
+pub async fn ping_heartbeat(heartbeat: &mut Heartbeat) -> Result<(), String> {
+    let SenderStored { keys, sets, .. } = heartbeat.sender.clone();
+
+    let keys: Vec<Key> = keys
+        .iter()
+        .map(|key| Key {
+            host: key.host.clone(),
+            pk: key.pk.clone(),
+            id: key.id.clone(),
+        })
+        .collect();
+
+    let (sender_state, health) = ping_secret(Sender { keys, sets }).await?;
+
+    // sender metas are stored outside of the vault, pings are recorded even while it is locked
+    match record_sender_ping(&heartbeat.sender, &health, get_current_unix_time()) {
+        Ok(sender_meta) => heartbeat.meta = Some(sender_meta),
+        Err(e) => tracing::error!("error recording ping: {:?}", e),
+    }
+
+    heartbeat.state = Some(sender_state);
+    heartbeat.keys_health = Some(health);
+
+    Ok(())
+}
@@ -0,0 +1,14 @@
+use std::collections::HashMap;
+
+use dioxus::prelude::*;
+
+use wingedcap::get_current_unix_time;
+
+use crate::types::Heartbeat;
+
+// the heartbeat scheduler pings the sender on its next tick
+pub fn request_ping(mut heartbeats: Signal<HashMap<String, Heartbeat>>, sender_id: &str) {
+    if let Some(heartbeat) = heartbeats.write().get_mut(sender_id) {
+        heartbeat.next_ping = Some(get_current_unix_time());
+    }
+}
@@ -0,0 +1,126 @@
+use std::collections::{HashMap, HashSet};
+
+use dioxus::prelude::*;
+
+use wingedcap::get_current_unix_time;
+
+use crate::constants::HEARTBEAT_SCHEDULER_TICK;
+
+use crate::storage::{get_sender_id, get_sender_meta, get_senders, get_settings, is_vault_locked};
+
+use crate::types::Heartbeat;
+
+use crate::utils::{
+    get_heartbeat_fraction, get_heartbeat_interval, ping_heartbeat, time_to_seconds, wait,
+};
+
+// pings every stored sender in the background into the Heartbeat context, by sender id,
+// the senders read while the vault was unlocked keep being pinged once it locks again,
+// each in its own task so that a slow server doesn't hold back the other senders
+pub fn use_heartbeat_scheduler() {
+    let mut heartbeats = use_context::<Signal<HashMap<String, Heartbeat>>>();
+
+    // ids of the senders whose ping is under way
+    let mut pinging: Signal<HashSet<String>> = use_signal(HashSet::new);
+
+    use_future(move || async move {
+        loop {
+            wait(HEARTBEAT_SCHEDULER_TICK).await;
+
+            if !is_vault_locked() {
+                match get_senders() {
+                    Ok(senders) => {
+                        let senders: HashMap<_, _> = senders
+                            .records
+                            .into_iter()
+                            .filter_map(|(_, sender)| Some((get_sender_id(&sender).ok()?, sender)))
+                            .collect();
+
+                        let is_changed = {
+                            let current = heartbeats.peek();
+
+                            current.len() != senders.len()
+                                || senders.iter().any(|(id, sender)| {
+                                    current
+                                        .get(id)
+                                        .is_none_or(|heartbeat| heartbeat.sender != *sender)
+                                })
+                        };
+
+                        if is_changed {
+                            heartbeats.with_mut(|heartbeats| {
+                                heartbeats.retain(|id, _| senders.contains_key(id));
+
+                                for (id, sender) in senders {
+                                    match heartbeats.get_mut(&id) {
+                                        Some(heartbeat) => heartbeat.sender = sender,
+                                        None => {
+                                            let meta = get_sender_meta(&sender).ok();
+
+                                            heartbeats.insert(
+                                                id,
+                                                Heartbeat {
+                                                    sender,
+                                                    state: None,
+                                                    keys_health: None,
+                                                    next_ping: None,
+                                                    meta,
+                                                },
+                                            );
+                                        }
+                                    }
+                                }
+                            });
+                        }
+                    }
+
+                    Err(e) => {
+                        tracing::error!("error loading senders: {:?}", e);
+                    }
+                }
+            }
+
+            let now = get_current_unix_time();
+
+            let due: Vec<(String, Heartbeat)> = heartbeats
+                .peek()
+                .iter()
+                .filter(|(id, heartbeat)| {
+                    !pinging.peek().contains(*id)
+                        && heartbeat.next_ping.is_none_or(|next_ping| next_ping <= now)
+                })
+                .map(|(id, heartbeat)| (id.clone(), heartbeat.clone()))
+                .collect();
+
+            for (id, mut heartbeat) in due {
+                pinging.write().insert(id.clone());
+
+                spawn(async move {
+                    if let Err(e) = ping_heartbeat(&mut heartbeat).await {
+                        tracing::warn!("error pinging sender {}: {}", heartbeat.sender.label, e);
+                    }
+
+                    // senders from before the meta was kept are pinged as if they had the default timelock
+                    let timelock = heartbeat
+                        .meta
+                        .as_ref()
+                        .and_then(|meta| meta.timelock.clone())
+                        .unwrap_or_else(|| get_settings().unwrap_or_default().default_timelock);
+
+                    let interval =
+                        get_heartbeat_interval(time_to_seconds(timelock), get_heartbeat_fraction());
+
+                    // the sender may have been removed meanwhile
+                    if let Some(current) = heartbeats.write().get_mut(&id) {
+                        current.state = heartbeat.state;
+                        current.keys_health = heartbeat.keys_health;
+                        current.meta = heartbeat.meta;
+                        current.next_ping = Some(get_current_unix_time() + interval as i64);
+                    }
+
+                    pinging.write().remove(&id);
+                });
+            }
+        }
+    });
+}
@@ -0,0 +1,142 @@
+use std::collections::HashMap;
+
+use dioxus::prelude::*;
+
+use wingedcap::{
+    client::{Key, Receiver, ReceiverState, ReceiverStored},
+    get_current_unix_time,
+};
+
+use crate::constants::{MAX_RECEIVER_POLL_BACKOFF, RECEIVER_POLL_TICK};
+
+use crate::storage::{
+    get_receiver_id, get_receiver_meta, get_receivers, is_vault_locked, record_receiver_unlock,
+};
+
+use crate::types::{ReceiverPoll, Settings};
+
+use crate::utils::{get_secret, wait};
+
+// polls every stored receiver in the background into the ReceiverPoll context, backing off
+// while its keys can't be reached, on_unlock gets the receivers seen unlocked for the first time
+pub fn use_receiver_poller(on_unlock: Callback<ReceiverStored>) {
+    let settings = use_context::<Signal<Settings>>();
+
+    let mut polls = use_context::<Signal<HashMap<String, ReceiverPoll>>>();
+
+    let handle_first_unlock = move |receiver: &ReceiverStored| {
+        on_unlock.call(receiver.clone());
+
+        #[cfg(all(feature = "desktop", target_os = "linux"))]
+        spawn({
+            let body = format!("\"{}\" has unlocked", receiver.label);
+
+            async move {
+                use crate::utils::show_native_notification;
+
+                if let Err(e) = show_native_notification("Secret unlocked", &body, None).await {
+                    tracing::warn!("error showing notification: {:?}", e);
+                }
+            }
+        });
+    };
+
+    use_future(move || async move {
+        // next poll time and consecutive failures, by receiver id
+        let mut schedules: HashMap<String, (i64, u32)> = HashMap::new();
+
+        loop {
+            wait(RECEIVER_POLL_TICK).await;
+
+            let interval = settings.peek().receiver_poll_interval;
+
+            if interval == 0 || is_vault_locked() {
+                continue;
+            }
+
+            let Ok(receivers) = get_receivers() else {
+                continue;
+            };
+
+            for (_, receiver) in receivers.records {
+                let Ok(id) = get_receiver_id(&receiver) else {
+                    continue;
+                };
+
+                let now = get_current_unix_time();
+
+                let (next_poll_at, failures) = schedules.get(&id).copied().unwrap_or((now, 0));
+
+                if next_poll_at > now {
+                    continue;
+                }
+
+                let ReceiverStored { keys, sets, .. } = receiver.clone();
+
+                let keys: Vec<Key> = keys
+                    .iter()
+                    .map(|key| Key {
+                        host: key.host.clone(),
+                        pk: key.pk.clone(),
+                        id: key.id.clone(),
+                    })
+                    .collect();
+
+                let failures = match get_secret(Receiver { keys, sets }).await {
+                    Ok((state, keys_health)) => {
+                        let unlocked_at = match state {
+                            ReceiverState::Unlocked { .. } => {
+                                match record_receiver_unlock(&receiver, get_current_unix_time()) {
+                                    Ok((unlocked_at, is_first_unlock)) => {
+                                        if is_first_unlock {
+                                            handle_first_unlock(&receiver);
+                                        }
+
+                                        Some(unlocked_at)
+                                    }
+
+                                    Err(e) => {
+                                        tracing::error!("error recording unlock: {:?}", e);
+                                        None
+                                    }
+                                }
+                            }
+
+                            ReceiverState::Locked { .. } => get_receiver_meta(&receiver)
+                                .ok()
+                                .and_then(|meta| meta.unlocked_at),
+                        };
+
+                        let is_reachable = keys_health.iter().any(|health| health.is_reachable());
+
+                        polls.write().insert(
+                            id.clone(),
+                            ReceiverPoll {
+                                state,
+                                keys_health,
+                                unlocked_at,
+                            },
+                        );
+
+                        if is_reachable {
+                            0
+                        } else {
+                            failures + 1
+                        }
+                    }
+
+                    Err(e) => {
+                        tracing::warn!("error polling receiver {}: {}", receiver.label, e);
+                        failures + 1
+                    }
+                };
+
+                let backoff = interval
+                    .saturating_mul(1 << failures.min(16))
+                    .min(MAX_RECEIVER_POLL_BACKOFF.max(interval));
+
+                schedules.insert(id, (get_current_unix_time() + backoff as i64, failures));
+            }
+        }
+    });
+}
@@ -0,0 +1,128 @@
+use std::collections::{HashMap, HashSet};
+
+use dioxus::prelude::*;
+
+use wingedcap::client::SenderState;
+
+use crate::constants::UNLOCK_WARNING_CHECK_INTERVAL;
+
+use crate::types::{Heartbeat, Settings};
+
+use crate::utils::{
+    format_countdown, get_key_unlock_times, get_projected_unlock, time_to_seconds, use_now,
+};
+
+// warns once per configured threshold the projected unlock of each sender drops below
+// since its last successful ping, which arms the warnings again; thresholds as long as
+// the timelock are ignored, on_warning gets the sender id and the message to show in app,
+// desktop notifications are shown here
+pub fn use_unlock_warnings(on_warning: Callback<(String, String)>) {
+    let settings = use_context::<Signal<Settings>>();
+
+    let heartbeats = use_context::<Signal<HashMap<String, Heartbeat>>>();
+
+    let now = use_now(UNLOCK_WARNING_CHECK_INTERVAL);
+
+    // sender id, last ping and threshold of each warning already shown
+    let mut warned: Signal<HashSet<(String, Option<i64>, u64)>> = use_signal(HashSet::new);
+
+    use_effect(move || {
+        let now = now();
+
+        for (sender_id, heartbeat) in heartbeats.peek().iter() {
+            let Some(meta) = heartbeat.meta.as_ref() else {
+                continue;
+            };
+
+            if matches!(heartbeat.state, Some(SenderState::Unlocked { .. })) {
+                continue;
+            }
+
+            let sender = &heartbeat.sender;
+
+            let key_unlock_times = get_key_unlock_times(meta, sender.keys.len());
+
+            let Some(unlock_at) = get_projected_unlock(&key_unlock_times, &sender.sets) else {
+                continue;
+            };
+
+            let Some(countdown) = format_countdown(unlock_at, &now) else {
+                continue;
+            };
+
+            let remaining = (unlock_at - now).num_seconds() as u64;
+
+            let last_ping = meta.last_ping().or(meta.created_at);
+
+            let timelock = meta.timelock.clone().map(time_to_seconds);
+
+            // thresholds the remaining time went below since the last ping, not warned about yet
+            let crossed: Vec<u64> = settings
+                .peek()
+                .unlock_warnings
+                .iter()
+                .copied()
+                .filter(|threshold| timelock.is_none_or(|timelock| *threshold < timelock))
+                .filter(|threshold| remaining <= *threshold)
+                .filter(|threshold| {
+                    last_ping.is_none_or(|last_ping| {
+                        unlock_at.timestamp() - *threshold as i64 > last_ping
+                    })
+                })
+                .filter(|threshold| {
+                    !warned
+                        .peek()
+                        .contains(&(sender_id.clone(), last_ping, *threshold))
+                })
+                .collect();
+
+            if crossed.is_empty() {
+                continue;
+            }
+
+            // warnings from before the last ping are done with, the crossed thresholds
+            // are warned about at once
+            {
+                let mut warned = warned.write();
+
+                warned.retain(|(warned_sender_id, warned_last_ping, _)| {
+                    warned_sender_id != sender_id || *warned_last_ping == last_ping
+                });
+
+                warned.extend(
+                    crossed
+                        .into_iter()
+                        .map(|threshold| (sender_id.clone(), last_ping, threshold)),
+                );
+            }
+
+            let message = format!(
+                "\"{}\" unlocks in {} unless it is pinged",
+                sender.label, countdown
+            );
+
+            on_warning.call((sender_id.clone(), message.clone()));
+
+            #[cfg(all(feature = "desktop", target_os = "linux"))]
+            spawn({
+                let sender_id = sender_id.clone();
+
+                async move {
+                    use crate::utils::{request_ping, show_native_notification};
+
+                    match show_native_notification(
+                        "Secret about to unlock",
+                        &message,
+                        Some("Ping now"),
+                    )
+                    .await
+                    {
+                        Ok(true) => request_ping(heartbeats, &sender_id),
+                        Ok(false) => {}
+                        Err(e) => tracing::warn!("error showing notification: {:?}", e),
+                    }
+                }
+            });
+        }
+    });
+}
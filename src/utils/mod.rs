@@ -4,5 +4,39 @@ pub use time::*;
 pub mod secret;
 pub use secret::*;
 
+pub mod heartbeat;
+pub use heartbeat::*;
+
+#[cfg(any(feature = "desktop", feature = "web", feature = "mobile"))]
+pub mod clipboard;
+#[cfg(any(feature = "desktop", feature = "web", feature = "mobile"))]
+pub use clipboard::*;
+
 pub mod use_unique_id_util;
 pub use use_unique_id_util::*;
+
+pub mod format_byte_size_util;
+pub use format_byte_size_util::*;
+
+pub mod render_markdown_util;
+pub use render_markdown_util::*;
+
+pub mod vault;
+pub use vault::*;
+
+pub mod file;
+pub use file::*;
+
+pub mod qr;
+pub use qr::*;
+
+pub mod shamir;
+pub use shamir::*;
+
+pub mod share;
+pub use share::*;
+
+#[cfg(all(feature = "desktop", target_os = "linux"))]
+pub mod notification;
+#[cfg(all(feature = "desktop", target_os = "linux"))]
+pub use notification::*;
@@ -0,0 +1,2 @@
+pub mod show_native_notification_util;
+pub use show_native_notification_util::*;
@@ -0,0 +1,42 @@
+use futures::channel::oneshot;
+
+use notify_rust::{Notification, Timeout};
+
+use crate::constants::APP_NAME;
+
+const NOTIFICATION_ACTION: &str = "default";
+
+// resolves to true once the action is clicked, false when the notification is dismissed
+// or right away when it has no action
+pub async fn show_native_notification(
+    summary: &str,
+    body: &str,
+    action_label: Option<&str>,
+) -> Result<bool, String> {
+    let mut notification = Notification::new();
+
+    notification.appname(APP_NAME).summary(summary).body(body);
+
+    let Some(action_label) = action_label else {
+        notification.show().map_err(|e| e.to_string())?;
+
+        return Ok(false);
+    };
+
+    let handle = notification
+        .action(NOTIFICATION_ACTION, action_label)
+        .timeout(Timeout::Never)
+        .show()
+        .map_err(|e| e.to_string())?;
+
+    let (sender, receiver) = oneshot::channel();
+
+    // waiting for the action blocks on the session bus
+    std::thread::spawn(move || {
+        handle.wait_for_action(|action| {
+            let _ = sender.send(action == NOTIFICATION_ACTION);
+        });
+    });
+
+    receiver.await.map_err(|e| e.to_string())
+}
@@ -0,0 +1,19 @@
+// every QR code found in a png or jpeg image
+pub fn decode_qr_image(image_bytes: &[u8]) -> Result<Vec<String>, String> {
+    let image = image::load_from_memory(image_bytes).map_err(|e| e.to_string())?;
+
+    let mut prepared_image = rqrr::PreparedImage::prepare(image.to_luma8());
+
+    let contents: Vec<String> = prepared_image
+        .detect_grids()
+        .into_iter()
+        .filter_map(|grid| grid.decode().ok())
+        .map(|(_, content)| content)
+        .collect();
+
+    if contents.is_empty() {
+        return Err("no QR code found in the image".to_string());
+    }
+
+    Ok(contents)
+}
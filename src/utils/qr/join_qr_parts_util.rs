@@ -0,0 +1,79 @@
+use crate::constants::{MAX_RECEIVER_QR_PARTS, QR_PART_PREFIX};
+
+// rebuilds a payload from its decoded parts, in any order and with duplicates
+pub fn join_qr_parts(parts: Vec<String>) -> Result<String, String> {
+    let prefixed_parts: Vec<&String> = parts
+        .iter()
+        .filter(|part| part.starts_with(&format!("{} ", QR_PART_PREFIX)))
+        .collect();
+
+    if prefixed_parts.is_empty() {
+        return match parts.as_slice() {
+            [part] => Ok(part.clone()),
+            [] => Err("no QR code found".to_string()),
+            _ => Err("found several unrelated QR codes".to_string()),
+        };
+    }
+
+    let mut total = None;
+    let mut chunks: Vec<Option<String>> = vec![];
+
+    for part in prefixed_parts {
+        let invalid_part = || format!("invalid QR code part \"{}\"", part);
+
+        let mut fields = part.splitn(3, ' ').skip(1);
+
+        let (index, part_total) = fields
+            .next()
+            .and_then(|position| position.split_once('/'))
+            .and_then(|(index, total)| {
+                Some((index.parse::<usize>().ok()?, total.parse::<usize>().ok()?))
+            })
+            .ok_or_else(invalid_part)?;
+
+        let chunk = fields.next().unwrap_or_default();
+
+        // checked before allocating, a crafted code could otherwise ask for any number of parts
+        if part_total == 0 || part_total > MAX_RECEIVER_QR_PARTS {
+            return Err(format!(
+                "QR code part \"{}\" announces {} parts, at most {} are supported",
+                part, part_total, MAX_RECEIVER_QR_PARTS
+            ));
+        }
+
+        if index == 0 || index > part_total {
+            return Err(invalid_part());
+        }
+
+        if total.is_some_and(|total| total != part_total) {
+            return Err(format!(
+                "QR code part \"{}\" belongs to another set of codes",
+                part
+            ));
+        }
+
+        if total.is_none() {
+            total = Some(part_total);
+            chunks = vec![None; part_total];
+        }
+
+        chunks[index - 1] = Some(chunk.to_string());
+    }
+
+    let missing: Vec<String> = chunks
+        .iter()
+        .enumerate()
+        .filter(|(_, chunk)| chunk.is_none())
+        .map(|(index, _)| (index + 1).to_string())
+        .collect();
+
+    if !missing.is_empty() {
+        return Err(format!(
+            "missing QR code part(s) {} of {}",
+            missing.join(", "),
+            chunks.len()
+        ));
+    }
+
+    Ok(chunks.into_iter().flatten().collect())
+}
@@ -0,0 +1,11 @@
+pub mod split_qr_parts_util;
+pub use split_qr_parts_util::*;
+
+pub mod join_qr_parts_util;
+pub use join_qr_parts_util::*;
+
+pub mod render_qr_svg_util;
+pub use render_qr_svg_util::*;
+
+pub mod decode_qr_image_util;
+pub use decode_qr_image_util::*;
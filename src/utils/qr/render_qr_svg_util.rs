@@ -0,0 +1,12 @@
+use qrcode::{render::svg, EcLevel, QrCode};
+
+pub fn render_qr_svg(data: &str) -> Result<String, String> {
+    let code = QrCode::with_error_correction_level(data, EcLevel::M).map_err(|e| e.to_string())?;
+
+    Ok(code
+        .render::<svg::Color>()
+        .min_dimensions(256, 256)
+        .dark_color(svg::Color("#000000"))
+        .light_color(svg::Color("#ffffff"))
+        .build())
+}
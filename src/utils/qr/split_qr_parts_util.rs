@@ -0,0 +1,23 @@
+use crate::constants::{QR_PART_MAX_LENGTH, QR_PART_PREFIX};
+
+// a payload that fits in one code is left as is so that any scanner can read it
+pub fn split_qr_parts(payload: &str) -> Vec<String> {
+    let chars: Vec<char> = payload.chars().collect();
+
+    if chars.len() <= QR_PART_MAX_LENGTH {
+        return vec![payload.to_string()];
+    }
+
+    let chunks: Vec<String> = chars
+        .chunks(QR_PART_MAX_LENGTH)
+        .map(|chunk| chunk.iter().collect())
+        .collect();
+
+    let total = chunks.len();
+
+    chunks
+        .into_iter()
+        .enumerate()
+        .map(|(index, chunk)| format!("{} {}/{} {}", QR_PART_PREFIX, index + 1, total, chunk))
+        .collect()
+}
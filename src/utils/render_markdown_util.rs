@@ -0,0 +1,19 @@
+use pulldown_cmark::{html, Event, Options, Parser};
+
+// raw html is shown as text, the preview only renders markdown
+pub fn render_markdown(markdown: &str) -> String {
+    let parser = Parser::new_ext(
+        markdown,
+        Options::ENABLE_STRIKETHROUGH | Options::ENABLE_TABLES,
+    )
+    .map(|event| match event {
+        Event::Html(html) | Event::InlineHtml(html) => Event::Text(html),
+        event => event,
+    });
+
+    let mut rendered = String::new();
+
+    html::push_html(&mut rendered, parser);
+
+    rendered
+}
@@ -0,0 +1,66 @@
+use wingedcap::client::{
+    KeyIndexArray, KeyWithMeta, Receiver, SenderStored, Server, ServerWithMeta,
+};
+
+use crate::types::{SetKeyOutcome, SetSecretError, SetSecretOptions, Time};
+
+use crate::utils::{set_secret, time_to_seconds, validate_vault_sets};
+
+pub async fn create_secret(
+    label: String,
+    message: String,
+    timelock: Time,
+    servers_with_meta: Vec<ServerWithMeta>,
+    sets: Vec<KeyIndexArray>,
+    options: &SetSecretOptions,
+) -> Result<(SenderStored, Receiver, Vec<SetKeyOutcome>), SetSecretError> {
+    let timelock = time_to_seconds(timelock);
+
+    let servers: Vec<Server> = servers_with_meta
+        .iter()
+        .map(|ServerWithMeta { host, pk, .. }| Server {
+            host: host.clone(),
+            pk: pk.clone(),
+        })
+        .collect();
+
+    validate_vault_sets(&sets, servers.len() as u64).map_err(|message| SetSecretError {
+        message,
+        outcomes: vec![],
+    })?;
+
+    let (sender, receiver, outcomes) =
+        set_secret(message, timelock, servers, sets, options).await?;
+
+    // servers that failed have no key in the sender
+    let kept_servers_with_meta: Vec<ServerWithMeta> = servers_with_meta
+        .into_iter()
+        .zip(outcomes.iter())
+        .filter(|(_, outcome)| outcome.error.is_none())
+        .map(|(server_with_meta, _)| server_with_meta)
+        .collect();
+
+    let keys_with_meta = sender
+        .keys
+        .iter()
+        .enumerate()
+        .map(|(index, key)| {
+            let ServerWithMeta { meta, .. } = kept_servers_with_meta[index].clone();
+
+            KeyWithMeta {
+                host: key.host.clone(),
+                pk: key.pk.clone(),
+                id: key.id.clone(),
+                meta: meta.clone(),
+            }
+        })
+        .collect();
+
+    let sender_stored = SenderStored {
+        label,
+        keys: keys_with_meta,
+        sets: sender.sets.clone(),
+    };
+
+    Ok((sender_stored, receiver, outcomes))
+}
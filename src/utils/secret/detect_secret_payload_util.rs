@@ -0,0 +1,31 @@
+use serde_json::Value;
+
+use crate::constants::{FILE_ENVELOPE_PREFIX, PASSPHRASE_ENVELOPE_PREFIX};
+
+use crate::types::SecretPayload;
+
+use crate::utils::decode_file_envelope;
+
+pub fn detect_secret_payload(message: &str) -> SecretPayload {
+    if message.starts_with(PASSPHRASE_ENVELOPE_PREFIX) {
+        return SecretPayload::PassphraseProtected(message.to_string());
+    }
+
+    // a damaged envelope is still shown as text rather than hidden
+    if message.starts_with(FILE_ENVELOPE_PREFIX) {
+        if let Ok(attachment) = decode_file_envelope(message) {
+            return SecretPayload::File(attachment);
+        }
+    }
+
+    let trimmed = message.trim();
+
+    // plain numbers or strings are valid json too, only objects and arrays are worth formatting
+    if trimmed.starts_with('{') || trimmed.starts_with('[') {
+        if let Ok(value) = serde_json::from_str::<Value>(trimmed) {
+            return SecretPayload::Json(value);
+        }
+    }
+
+    SecretPayload::Text(message.to_string())
+}
@@ -0,0 +1,34 @@
+use wingedcap::client::{Key, KeyIndexArray, Receiver, ReceiverKeySet, ServerWithMeta};
+
+use crate::constants::KEY_ID_LENGTH;
+
+// length of the receiver json a message would end up in, before the keys are even set
+pub fn estimate_receiver_size(
+    message_length: usize,
+    servers: &[ServerWithMeta],
+    sets: &[KeyIndexArray],
+) -> usize {
+    let keys = servers
+        .iter()
+        .map(|server| Key {
+            host: server.host.clone(),
+            pk: server.pk.clone(),
+            id: "0".repeat(KEY_ID_LENGTH),
+        })
+        .collect();
+
+    // each set holds its own copy of the message, aes-gcm adds a 16 bytes tag and it is hex encoded
+    let data = "0".repeat((message_length + 16) * 2);
+
+    let sets = sets
+        .iter()
+        .map(|keys| ReceiverKeySet {
+            keys: keys.clone(),
+            data: data.clone(),
+        })
+        .collect();
+
+    serde_json::to_string(&Receiver { keys, sets })
+        .map(|receiver_json| receiver_json.len())
+        .unwrap_or_default()
+}
@@ -0,0 +1,5 @@
+pub fn get_default_required_keys(total_keys: u64, ratio: f64) -> u64 {
+    let required_keys = (total_keys as f64 * ratio.clamp(0.0, 1.0)).ceil() as u64;
+
+    required_keys.clamp(1, total_keys.max(1))
+}
@@ -8,7 +8,11 @@ use wingedcap::{
     GetKeyInput, GetKeyOutput, GetKeyOutputUnlocked,
 };
 
-pub async fn get_secret(receiver: Receiver) -> Result<ReceiverState, String> {
+use crate::types::{KeyHealth, KeyHealthStatus};
+
+use crate::utils::get_current_unix_time_millis;
+
+pub async fn get_secret(receiver: Receiver) -> Result<(ReceiverState, Vec<KeyHealth>), String> {
     let Receiver { keys, sets } = receiver;
 
     let get_futures: Vec<_> = keys
@@ -24,17 +28,33 @@ pub async fn get_secret(receiver: Receiver) -> Result<ReceiverState, String> {
             let get_input = GetKeyInput { id: id.clone() };
 
             async move {
+                let started_at = get_current_unix_time_millis();
                 let get_result = get_key(&server, &get_input).await;
-                (key.clone(), get_result)
+                let latency = (get_current_unix_time_millis() - started_at).max(0) as u64;
+
+                (key.clone(), get_result, latency)
             }
         })
         .collect();
 
     let get_results = join_all(get_futures).await;
 
+    let keys_health: Vec<KeyHealth> = get_results
+        .iter()
+        .map(|(key, get_result, latency)| KeyHealth {
+            key: key.clone(),
+            status: match get_result {
+                Ok(GetKeyOutput::Locked) => KeyHealthStatus::Locked,
+                Ok(GetKeyOutput::Unlocked(_)) => KeyHealthStatus::Unlocked,
+                Err(e) => KeyHealthStatus::Unreachable(e.clone()),
+            },
+            latency: *latency,
+        })
+        .collect();
+
     let keys_state: Vec<ReceiverKeyState> = get_results
         .into_iter()
-        .map(|(key, get_result)| {
+        .map(|(key, get_result, _)| {
             let Key { host, pk, id } = key;
 
             match get_result {
@@ -46,7 +66,7 @@ pub async fn get_secret(receiver: Receiver) -> Result<ReceiverState, String> {
         })
         .collect();
 
-    let receiver_state_result = process_receiver_state(keys_state, sets).await;
+    let state = process_receiver_state(keys_state, sets).await?;
 
-    receiver_state_result
+    Ok((state, keys_health))
 }
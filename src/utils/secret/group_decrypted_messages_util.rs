@@ -0,0 +1,13 @@
+// distinct messages along with the indexes of the unlocked sets they came from, in order of appearance
+pub fn group_decrypted_messages(decrypted_messages: &[String]) -> Vec<(String, Vec<usize>)> {
+    let mut groups: Vec<(String, Vec<usize>)> = vec![];
+
+    for (set_index, message) in decrypted_messages.iter().enumerate() {
+        match groups.iter_mut().find(|(existing, _)| existing == message) {
+            Some((_, set_indexes)) => set_indexes.push(set_index),
+            None => groups.push((message.clone(), vec![set_index])),
+        }
+    }
+
+    groups
+}
@@ -6,3 +6,30 @@ pub use set_secret_util::*;
 
 mod get_secret_util;
 pub use get_secret_util::*;
+
+mod create_secret_util;
+pub use create_secret_util::*;
+
+mod get_default_required_keys_util;
+pub use get_default_required_keys_util::*;
+
+mod set_key_with_retry_util;
+pub use set_key_with_retry_util::*;
+
+mod remap_vault_sets_util;
+pub use remap_vault_sets_util::*;
+
+mod remap_partial_vault_sets_util;
+pub use remap_partial_vault_sets_util::*;
+
+mod validate_vault_sets_util;
+pub use validate_vault_sets_util::*;
+
+mod detect_secret_payload_util;
+pub use detect_secret_payload_util::*;
+
+mod group_decrypted_messages_util;
+pub use group_decrypted_messages_util::*;
+
+mod estimate_receiver_size_util;
+pub use estimate_receiver_size_util::*;
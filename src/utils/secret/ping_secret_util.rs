@@ -4,7 +4,11 @@ use wingedcap::{
     PingKeyInput, PingKeyOutput,
 };
 
-pub async fn ping_secret(sender: Sender) -> Result<SenderState, String> {
+use crate::types::{KeyHealth, KeyHealthStatus};
+
+use crate::utils::get_current_unix_time_millis;
+
+pub async fn ping_secret(sender: Sender) -> Result<(SenderState, Vec<KeyHealth>), String> {
     let Sender { keys, sets } = sender;
 
     let ping_futures: Vec<_> = keys
@@ -20,23 +24,40 @@ pub async fn ping_secret(sender: Sender) -> Result<SenderState, String> {
             let ping_input = PingKeyInput { id: id.clone() };
 
             async move {
+                let started_at = get_current_unix_time_millis();
                 let ping_result = ping_key(&server, &ping_input).await;
-                (key.clone(), ping_result)
+                let latency = (get_current_unix_time_millis() - started_at).max(0) as u64;
+
+                (key.clone(), ping_result, latency)
             }
         })
         .collect();
 
     let ping_results = join_all(ping_futures).await;
 
+    let keys_health: Vec<KeyHealth> = ping_results
+        .iter()
+        .map(|(key, ping_result, latency)| KeyHealth {
+            key: key.clone(),
+            status: match ping_result {
+                Ok(PingKeyOutput::Locked) => KeyHealthStatus::Locked,
+                Ok(PingKeyOutput::Unlocked) => KeyHealthStatus::Unlocked,
+                Err(e) => KeyHealthStatus::Unreachable(e.clone()),
+            },
+            latency: *latency,
+        })
+        .collect();
+
+    // the sdk has no notion of unreachable keys, they can't be counted as unlocked though
     let keys_state: Vec<SenderKeyState> = ping_results
         .into_iter()
-        .map(|(key, ping_result)| match ping_result {
+        .map(|(key, ping_result, _)| match ping_result {
             Err(_) | Ok(PingKeyOutput::Locked) => SenderKeyState::Locked(key),
             Ok(PingKeyOutput::Unlocked) => SenderKeyState::Unlocked(key),
         })
         .collect();
 
-    let ping_secret_result = process_sender_state(keys_state, sets).await;
+    let state = process_sender_state(keys_state, sets).await?;
 
-    ping_secret_result
+    Ok((state, keys_health))
 }
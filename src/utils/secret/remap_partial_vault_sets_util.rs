@@ -0,0 +1,63 @@
+use wingedcap::client::KeyIndexArray;
+
+use super::remap_vault_sets;
+
+// sets left once some keys failed to be set, with the indexes of the keys they still use;
+// a surviving key that is in no remaining set is dropped, it would be pinged for nothing
+pub fn remap_partial_vault_sets(
+    sets: Vec<KeyIndexArray>,
+    succeeded_indexes: &[u64],
+) -> (Vec<u64>, Vec<KeyIndexArray>) {
+    let sets = remap_vault_sets(sets, succeeded_indexes);
+
+    let used_positions: Vec<u64> = (0..succeeded_indexes.len() as u64)
+        .filter(|position| sets.iter().any(|set| set.contains(position)))
+        .collect();
+
+    let kept_indexes = used_positions
+        .iter()
+        .map(|position| succeeded_indexes[*position as usize])
+        .collect();
+
+    (kept_indexes, remap_vault_sets(sets, &used_positions))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::validate_vault_sets;
+
+    use super::remap_partial_vault_sets;
+
+    #[test]
+    fn drops_surviving_keys_left_in_no_set() {
+        // key 1 failed, which takes the only set key 2 was in
+        let sets = vec![vec![0, 1], vec![1, 2], vec![0, 3]];
+
+        let (kept_indexes, sets) = remap_partial_vault_sets(sets, &[0, 2, 3]);
+
+        assert_eq!(kept_indexes, vec![0, 3]);
+        assert_eq!(sets, vec![vec![0, 1]]);
+        assert_eq!(
+            validate_vault_sets(&sets, kept_indexes.len() as u64),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn keeps_everything_when_every_key_is_set() {
+        let sets = vec![vec![0, 1], vec![1, 2]];
+
+        assert_eq!(
+            remap_partial_vault_sets(sets.clone(), &[0, 1, 2]),
+            (vec![0, 1, 2], sets)
+        );
+    }
+
+    #[test]
+    fn leaves_no_set_when_every_set_lost_a_key() {
+        let (kept_indexes, sets) = remap_partial_vault_sets(vec![vec![0, 1], vec![1, 2]], &[0, 2]);
+
+        assert!(kept_indexes.is_empty());
+        assert!(sets.is_empty());
+    }
+}
@@ -0,0 +1,18 @@
+use wingedcap::client::KeyIndexArray;
+
+// keeps the sets made only of the kept key indexes, pointing them at their position among the kept
+// keys; for standard vaults this is the same as generating the sets again for the kept keys
+pub fn remap_vault_sets(sets: Vec<KeyIndexArray>, kept_indexes: &[u64]) -> Vec<KeyIndexArray> {
+    sets.into_iter()
+        .filter_map(|set| {
+            set.iter()
+                .map(|index| {
+                    kept_indexes
+                        .iter()
+                        .position(|kept_index| kept_index == index)
+                        .map(|position| position as u64)
+                })
+                .collect()
+        })
+        .collect()
+}
@@ -0,0 +1,31 @@
+use wingedcap::{
+    client::{set_key, Server},
+    SetKeyInput, SetKeyOutput,
+};
+
+use crate::types::SetSecretOptions;
+
+use crate::utils::time::wait_util::wait;
+
+pub async fn set_key_with_retry(
+    server: &Server,
+    set_input: &SetKeyInput,
+    options: &SetSecretOptions,
+) -> (Result<SetKeyOutput, String>, u32) {
+    let mut attempts = 0;
+    let mut backoff = options.backoff;
+
+    loop {
+        attempts += 1;
+
+        let set_result = set_key(server, set_input).await;
+
+        if set_result.is_ok() || attempts > options.retries {
+            return (set_result, attempts);
+        }
+
+        wait(backoff).await;
+
+        backoff = backoff.saturating_mul(2);
+    }
+}
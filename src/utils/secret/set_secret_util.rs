@@ -1,40 +1,93 @@
 use futures::future::join_all;
 use wingedcap::{
-    client::{process_new_secret, set_key, KeyIndexArray, Receiver, Sender, Server},
+    client::{process_new_secret, KeyIndexArray, Receiver, Sender, Server},
     SetKeyInput, SetKeyOutput,
 };
 
+use crate::types::{SetKeyOutcome, SetSecretError, SetSecretOptions};
+
+use crate::utils::{remap_partial_vault_sets, set_key_with_retry, validate_vault_sets};
+
 pub async fn set_secret(
     message: String,
     timelock: u64,
     servers: Vec<Server>,
     sets: Vec<KeyIndexArray>,
-) -> Result<(Sender, Receiver), String> {
+    options: &SetSecretOptions,
+) -> Result<(Sender, Receiver, Vec<SetKeyOutcome>), SetSecretError> {
     let set_futures: Vec<_> = servers
         .iter()
         .map(|server| {
             let set_input = SetKeyInput { timelock };
 
             async move {
-                let set_result = set_key(server, &set_input).await;
-                (server.clone(), set_result)
+                let (set_result, attempts) = set_key_with_retry(server, &set_input, options).await;
+                (server.clone(), set_result, attempts)
             }
         })
         .collect();
 
     let set_results = join_all(set_futures).await;
 
-    let keys_result: Result<Vec<(Server, SetKeyOutput)>, String> = set_results
-        .into_iter()
-        .map(|(server, set_result)| match set_result {
-            Ok(set_output) => Ok((server, set_output)),
-            Err(e) => Err(e),
+    let outcomes: Vec<SetKeyOutcome> = set_results
+        .iter()
+        .map(|(server, set_result, attempts)| SetKeyOutcome {
+            server: server.clone(),
+            attempts: *attempts,
+            error: set_result.as_ref().err().cloned(),
         })
         .collect();
 
-    let keys = keys_result?;
+    let failed_count = outcomes
+        .iter()
+        .filter(|outcome| outcome.error.is_some())
+        .count();
+
+    let to_error = |message: String| SetSecretError {
+        message,
+        outcomes: outcomes.clone(),
+    };
+
+    if failed_count > 0 && !options.allow_partial {
+        return Err(to_error(format!(
+            "failed to set {} of {} keys",
+            failed_count,
+            servers.len()
+        )));
+    }
+
+    let succeeded_indexes: Vec<u64> = set_results
+        .iter()
+        .enumerate()
+        .filter(|(_, (_, set_result, _))| set_result.is_ok())
+        .map(|(index, _)| index as u64)
+        .collect();
+
+    let (kept_indexes, sets) = match failed_count {
+        0 => (succeeded_indexes, sets),
+        _ => remap_partial_vault_sets(sets, &succeeded_indexes),
+    };
+
+    let keys: Vec<(Server, SetKeyOutput)> = set_results
+        .into_iter()
+        .enumerate()
+        .filter(|(index, _)| kept_indexes.contains(&(*index as u64)))
+        .filter_map(|(_, (server, set_result, _))| Some((server, set_result.ok()?)))
+        .collect();
+
+    if sets.is_empty() {
+        return Err(to_error(format!(
+            "failed to set {} of {} keys, not enough left for the required keys",
+            failed_count,
+            servers.len()
+        )));
+    }
+
+    validate_vault_sets(&sets, keys.len() as u64).map_err(to_error)?;
 
-    let new_secret_result = process_new_secret(message, keys, sets).await;
+    let (sender, receiver) = process_new_secret(message, keys, sets)
+        .await
+        .map_err(to_error)?;
 
-    new_secret_result
+    Ok((sender, receiver, outcomes))
 }
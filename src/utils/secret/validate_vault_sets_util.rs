@@ -0,0 +1,35 @@
+use wingedcap::client::KeyIndexArray;
+
+pub fn validate_vault_sets(sets: &[KeyIndexArray], total_keys: u64) -> Result<(), String> {
+    if sets.is_empty() {
+        return Err("at least one set is required".to_string());
+    }
+
+    for (set_index, set) in sets.iter().enumerate() {
+        if set.is_empty() {
+            return Err(format!("set {} has no key", set_index + 1));
+        }
+
+        if let Some(key_index) = set.iter().find(|key_index| **key_index >= total_keys) {
+            return Err(format!(
+                "set {} uses key {} but there are only {} keys",
+                set_index + 1,
+                key_index + 1,
+                total_keys
+            ));
+        }
+
+        if (1..set.len()).any(|index| set[..index].contains(&set[index])) {
+            return Err(format!("set {} uses the same key twice", set_index + 1));
+        }
+    }
+
+    // a key in no set would be requested and pinged for nothing
+    if let Some(key_index) =
+        (0..total_keys).find(|key_index| !sets.iter().any(|set| set.contains(key_index)))
+    {
+        return Err(format!("key {} is not used by any set", key_index + 1));
+    }
+
+    Ok(())
+}
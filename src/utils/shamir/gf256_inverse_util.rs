@@ -0,0 +1,23 @@
+use super::gf256_mul;
+
+// a^254 is the inverse of a, since every non-zero a has a^255 = 1
+pub fn gf256_inverse(a: u8) -> Result<u8, String> {
+    if a == 0 {
+        return Err("zero has no inverse".to_string());
+    }
+
+    let mut result = 1;
+    let mut base = a;
+    let mut exponent = 254;
+
+    while exponent > 0 {
+        if exponent & 1 != 0 {
+            result = gf256_mul(result, base);
+        }
+
+        base = gf256_mul(base, base);
+        exponent >>= 1;
+    }
+
+    Ok(result)
+}
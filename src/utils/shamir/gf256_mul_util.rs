@@ -0,0 +1,22 @@
+// multiplication in GF(2^8), reduced by the AES polynomial x^8 + x^4 + x^3 + x + 1
+pub fn gf256_mul(mut a: u8, mut b: u8) -> u8 {
+    let mut product = 0;
+
+    while b != 0 {
+        if b & 1 != 0 {
+            product ^= a;
+        }
+
+        let carry = a & 0x80 != 0;
+
+        a <<= 1;
+
+        if carry {
+            a ^= 0x1b;
+        }
+
+        b >>= 1;
+    }
+
+    product
+}
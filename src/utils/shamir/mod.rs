@@ -0,0 +1,11 @@
+pub mod gf256_mul_util;
+pub use gf256_mul_util::*;
+
+pub mod gf256_inverse_util;
+pub use gf256_inverse_util::*;
+
+pub mod shamir_split_util;
+pub use shamir_split_util::*;
+
+pub mod shamir_combine_util;
+pub use shamir_combine_util::*;
@@ -0,0 +1,85 @@
+use super::{gf256_inverse, gf256_mul};
+
+// lagrange interpolation at 0, any threshold of the shares give the secret back
+pub fn shamir_combine(shares: &[(u8, Vec<u8>)]) -> Result<Vec<u8>, String> {
+    let Some((_, first)) = shares.first() else {
+        return Err("no share given".to_string());
+    };
+
+    if shares.iter().any(|(_, share)| share.len() != first.len()) {
+        return Err("the shares have different lengths".to_string());
+    }
+
+    let mut basis = vec![];
+
+    for (i, (x_i, _)) in shares.iter().enumerate() {
+        if *x_i == 0 {
+            return Err("invalid share index 0".to_string());
+        }
+
+        let mut numerator = 1;
+        let mut denominator = 1;
+
+        for (j, (x_j, _)) in shares.iter().enumerate() {
+            if i == j {
+                continue;
+            }
+
+            if x_i == x_j {
+                return Err(format!("share {} is given twice", x_i));
+            }
+
+            numerator = gf256_mul(numerator, *x_j);
+            denominator = gf256_mul(denominator, x_i ^ x_j);
+        }
+
+        basis.push(gf256_mul(numerator, gf256_inverse(denominator)?));
+    }
+
+    Ok((0..first.len())
+        .map(|position| {
+            shares
+                .iter()
+                .zip(&basis)
+                .fold(0, |secret, ((_, share), weight)| {
+                    secret ^ gf256_mul(share[position], *weight)
+                })
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::shamir_split;
+
+    use super::shamir_combine;
+
+    #[test]
+    fn round_trips_with_any_threshold_of_the_shares() {
+        let secret = b"receiver data".to_vec();
+
+        let shares = shamir_split(&secret, 2, 3).unwrap();
+
+        for pair in [[0, 1], [0, 2], [2, 1]] {
+            let points: Vec<_> = pair.iter().map(|i| shares[*i].clone()).collect();
+
+            assert_eq!(shamir_combine(&points), Ok(secret.clone()));
+        }
+    }
+
+    #[test]
+    fn too_few_shares_give_something_else() {
+        let secret = b"receiver data".to_vec();
+
+        let shares = shamir_split(&secret, 3, 3).unwrap();
+
+        assert_ne!(shamir_combine(&shares[..2]), Ok(secret));
+    }
+
+    #[test]
+    fn rejects_a_share_given_twice() {
+        let shares = shamir_split(b"receiver data", 2, 3).unwrap();
+
+        assert!(shamir_combine(&[shares[0].clone(), shares[0].clone()]).is_err());
+    }
+}
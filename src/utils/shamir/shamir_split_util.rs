@@ -0,0 +1,31 @@
+use super::gf256_mul;
+
+// one random polynomial of degree threshold - 1 per byte, share x holds every polynomial at x
+pub fn shamir_split(secret: &[u8], threshold: u8, total: u8) -> Result<Vec<(u8, Vec<u8>)>, String> {
+    if threshold < 2 {
+        return Err("at least 2 shares must be required".to_string());
+    }
+
+    if threshold > total {
+        return Err("more shares required than created".to_string());
+    }
+
+    let mut shares: Vec<(u8, Vec<u8>)> = (1..=total)
+        .map(|x| (x, Vec::with_capacity(secret.len())))
+        .collect();
+
+    for byte in secret {
+        let coefficients: Vec<u8> = (1..threshold).map(|_| rand::random()).collect();
+
+        for (x, share) in shares.iter_mut() {
+            let y = coefficients
+                .iter()
+                .rev()
+                .fold(0, |y, coefficient| gf256_mul(y, *x) ^ coefficient);
+
+            share.push(gf256_mul(y, *x) ^ byte);
+        }
+    }
+
+    Ok(shares)
+}
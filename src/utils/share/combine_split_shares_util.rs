@@ -0,0 +1,123 @@
+use std::collections::HashSet;
+
+use crate::types::SplitShare;
+
+use crate::utils::shamir_combine;
+
+// gives the receiver data back once enough shares of the same split are gathered
+pub fn combine_split_shares(shares: &[SplitShare]) -> Result<String, String> {
+    let Some(first) = shares.first() else {
+        return Err("no share given".to_string());
+    };
+
+    if shares.iter().any(|share| {
+        share.split_id != first.split_id
+            || share.threshold != first.threshold
+            || share.total != first.total
+    }) {
+        return Err("the shares come from different splits".to_string());
+    }
+
+    if first.threshold < 2 || first.threshold > first.total {
+        return Err(format!(
+            "invalid split, {} of {} shares required",
+            first.threshold, first.total
+        ));
+    }
+
+    let mut indexes = HashSet::new();
+
+    for share in shares {
+        if share.index == 0 || share.index > share.total {
+            return Err(format!("invalid share {} of {}", share.index, share.total));
+        }
+
+        if !indexes.insert(share.index) {
+            return Err(format!("share {} is given twice", share.index));
+        }
+    }
+
+    if indexes.len() < first.threshold as usize {
+        return Err(format!(
+            "{} of {} required shares given",
+            indexes.len(),
+            first.threshold
+        ));
+    }
+
+    let points: Vec<(u8, Vec<u8>)> = shares
+        .iter()
+        .map(|share| (share.index, share.data.clone()))
+        .collect();
+
+    let receiver_data = shamir_combine(&points)?;
+
+    String::from_utf8(receiver_data)
+        .map_err(|_| "the shares don't combine into receiver data".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::types::SplitShare;
+
+    use crate::utils::shamir_split;
+
+    use super::combine_split_shares;
+
+    const RECEIVER_DATA: &str = "{\"keys\":[],\"sets\":[]}";
+
+    fn split(threshold: u8, total: u8) -> Vec<SplitShare> {
+        shamir_split(RECEIVER_DATA.as_bytes(), threshold, total)
+            .unwrap()
+            .into_iter()
+            .map(|(index, data)| SplitShare {
+                split_id: "0badcafe".to_string(),
+                threshold,
+                index,
+                total,
+                data,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn any_threshold_of_the_shares_combine() {
+        let shares = split(3, 5);
+
+        assert_eq!(
+            combine_split_shares(&shares[..3]).as_deref(),
+            Ok(RECEIVER_DATA)
+        );
+        assert_eq!(
+            combine_split_shares(&[shares[4].clone(), shares[1].clone(), shares[3].clone()])
+                .as_deref(),
+            Ok(RECEIVER_DATA)
+        );
+    }
+
+    #[test]
+    fn rejects_too_few_shares() {
+        let shares = split(3, 5);
+
+        assert!(combine_split_shares(&shares[..2]).is_err());
+        assert!(
+            combine_split_shares(&[shares[0].clone(), shares[1].clone(), shares[1].clone()])
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn rejects_shares_that_disagree() {
+        let mut shares = split(2, 3);
+
+        shares[1].threshold = 3;
+
+        assert!(combine_split_shares(&shares).is_err());
+
+        let mut shares = split(2, 3);
+
+        shares[1].total = 4;
+
+        assert!(combine_split_shares(&shares).is_err());
+    }
+}
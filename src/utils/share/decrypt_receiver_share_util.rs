@@ -0,0 +1,81 @@
+use base64::{engine::general_purpose::STANDARD, Engine};
+
+use crate::constants::{RECEIVER_SHARE_BEGIN, RECEIVER_SHARE_END, RECEIVER_SHARE_VERSION};
+
+use crate::utils::{derive_vault_key, parse_kdf_params, vault_decrypt};
+
+// returns the receiver data as it was before encryption
+pub fn decrypt_receiver_share(share: &str, passphrase: &str) -> Result<String, String> {
+    let mut lines = share.lines().map(str::trim).filter(|line| !line.is_empty());
+
+    if lines.next() != Some(RECEIVER_SHARE_BEGIN) {
+        return Err("not an encrypted receiver share".to_string());
+    }
+
+    let version = lines
+        .next()
+        .and_then(|line| line.strip_prefix("Version:"))
+        .and_then(|version| version.trim().parse::<u32>().ok())
+        .ok_or("encrypted receiver share has no version")?;
+
+    if version != RECEIVER_SHARE_VERSION {
+        return Err(format!(
+            "encrypted receiver share version {} is not supported",
+            version
+        ));
+    }
+
+    let kdf = lines
+        .next()
+        .and_then(|line| line.strip_prefix("Kdf:"))
+        .ok_or("encrypted receiver share has no key derivation")?;
+
+    let kdf = parse_kdf_params(kdf.trim())?;
+
+    let mut body = String::new();
+    let mut is_complete = false;
+
+    for line in lines {
+        if line == RECEIVER_SHARE_END {
+            is_complete = true;
+            break;
+        }
+
+        body.push_str(line);
+    }
+
+    if !is_complete {
+        return Err("encrypted receiver share is incomplete".to_string());
+    }
+
+    let bytes = STANDARD.decode(body).map_err(|e| e.to_string())?;
+
+    if bytes.len() < 16 {
+        return Err("encrypted receiver share is too short".to_string());
+    }
+
+    let (salt, encrypted) = bytes.split_at(16);
+
+    let key = derive_vault_key(passphrase, salt, &kdf)?;
+
+    vault_decrypt(&key, &STANDARD.encode(encrypted))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::encrypt_receiver_share;
+
+    use super::decrypt_receiver_share;
+
+    #[test]
+    fn round_trips_with_the_stored_key_derivation() {
+        let share = encrypt_receiver_share("receiver data", "passphrase").unwrap();
+
+        assert!(share.contains("\nKdf: argon2id:v=19,m=19456,t=2,p=1\n"));
+        assert_eq!(
+            decrypt_receiver_share(&share, "passphrase").as_deref(),
+            Ok("receiver data")
+        );
+        assert!(decrypt_receiver_share(&share, "another passphrase").is_err());
+    }
+}
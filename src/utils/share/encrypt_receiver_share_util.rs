@@ -0,0 +1,40 @@
+use base64::{engine::general_purpose::STANDARD, Engine};
+
+use crate::constants::{
+    DEFAULT_KDF_PARAMS, RECEIVER_SHARE_BEGIN, RECEIVER_SHARE_END, RECEIVER_SHARE_LINE_LENGTH,
+    RECEIVER_SHARE_VERSION,
+};
+
+use crate::utils::{derive_vault_key, format_kdf_params, vault_encrypt};
+
+// keeps the receiver data unreadable in clipboard managers and chat histories it passes through
+pub fn encrypt_receiver_share(receiver_data: &str, passphrase: &str) -> Result<String, String> {
+    if passphrase.is_empty() {
+        return Err("passphrase is empty".to_string());
+    }
+
+    let salt: [u8; 16] = rand::random();
+
+    let key = derive_vault_key(passphrase, &salt, &DEFAULT_KDF_PARAMS)?;
+
+    let encrypted = STANDARD
+        .decode(vault_encrypt(&key, receiver_data)?)
+        .map_err(|e| e.to_string())?;
+
+    let body = STANDARD.encode([salt.as_slice(), encrypted.as_slice()].concat());
+
+    let lines: Vec<&str> = body
+        .as_bytes()
+        .chunks(RECEIVER_SHARE_LINE_LENGTH)
+        .map(|line| std::str::from_utf8(line).unwrap_or_default())
+        .collect();
+
+    Ok(format!(
+        "{}\nVersion: {}\nKdf: {}\n\n{}\n{}",
+        RECEIVER_SHARE_BEGIN,
+        RECEIVER_SHARE_VERSION,
+        format_kdf_params(&DEFAULT_KDF_PARAMS),
+        lines.join("\n"),
+        RECEIVER_SHARE_END
+    ))
+}
@@ -0,0 +1,5 @@
+use crate::constants::RECEIVER_SHARE_BEGIN;
+
+pub fn is_receiver_share(content: &str) -> bool {
+    content.trim_start().starts_with(RECEIVER_SHARE_BEGIN)
+}
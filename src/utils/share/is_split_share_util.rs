@@ -0,0 +1,7 @@
+use crate::constants::SPLIT_SHARE_PREFIX;
+
+pub fn is_split_share(content: &str) -> bool {
+    content
+        .trim_start()
+        .starts_with(&format!("{} ", SPLIT_SHARE_PREFIX))
+}
@@ -0,0 +1,23 @@
+pub mod encrypt_receiver_share_util;
+pub use encrypt_receiver_share_util::*;
+
+pub mod decrypt_receiver_share_util;
+pub use decrypt_receiver_share_util::*;
+
+pub mod is_receiver_share_util;
+pub use is_receiver_share_util::*;
+
+pub mod parse_receiver_data_util;
+pub use parse_receiver_data_util::*;
+
+pub mod split_receiver_data_util;
+pub use split_receiver_data_util::*;
+
+pub mod is_split_share_util;
+pub use is_split_share_util::*;
+
+pub mod parse_split_share_util;
+pub use parse_split_share_util::*;
+
+pub mod combine_split_shares_util;
+pub use combine_split_shares_util::*;
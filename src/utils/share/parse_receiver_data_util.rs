@@ -0,0 +1,12 @@
+use wingedcap::client::{Receiver, ReceiverStored};
+
+// takes shared receiver data as well as backups, which also carry the label
+pub fn parse_receiver_data(content: &str) -> Result<(Receiver, Option<String>), String> {
+    let label = serde_json::from_str::<ReceiverStored>(content)
+        .ok()
+        .map(|receiver| receiver.label);
+
+    serde_json::from_str::<Receiver>(content)
+        .map(|receiver| (receiver, label))
+        .map_err(|_| "this is not receiver data".to_string())
+}
@@ -0,0 +1,45 @@
+use base64::{engine::general_purpose::STANDARD, Engine};
+
+use crate::constants::SPLIT_SHARE_PREFIX;
+
+use crate::types::SplitShare;
+
+pub fn parse_split_share(content: &str) -> Result<SplitShare, String> {
+    let mut fields = content.split_whitespace();
+
+    if fields.next() != Some(SPLIT_SHARE_PREFIX) {
+        return Err("not a receiver data share".to_string());
+    }
+
+    let invalid_share = || "invalid receiver data share".to_string();
+
+    let split_id = fields.next().ok_or_else(invalid_share)?.to_string();
+
+    let threshold = fields
+        .next()
+        .and_then(|threshold| threshold.parse::<u8>().ok())
+        .ok_or_else(invalid_share)?;
+
+    let (index, total) = fields
+        .next()
+        .and_then(|position| position.split_once('/'))
+        .and_then(|(index, total)| Some((index.parse::<u8>().ok()?, total.parse::<u8>().ok()?)))
+        .ok_or_else(invalid_share)?;
+
+    let data = fields
+        .next()
+        .and_then(|data| STANDARD.decode(data).ok())
+        .ok_or_else(invalid_share)?;
+
+    if threshold < 2 || threshold > total || index == 0 || index > total {
+        return Err(invalid_share());
+    }
+
+    Ok(SplitShare {
+        split_id,
+        threshold,
+        index,
+        total,
+        data,
+    })
+}
@@ -0,0 +1,38 @@
+use base64::{engine::general_purpose::STANDARD, Engine};
+
+use crate::constants::{MAX_SPLIT_SHARES, SPLIT_SHARE_PREFIX};
+
+use crate::utils::shamir_split;
+
+// no single holder can rebuild the receiver data, any threshold of them together can
+pub fn split_receiver_data(
+    receiver_data: &str,
+    threshold: u8,
+    total: u8,
+) -> Result<Vec<String>, String> {
+    if total > MAX_SPLIT_SHARES {
+        return Err(format!(
+            "at most {} shares can be created",
+            MAX_SPLIT_SHARES
+        ));
+    }
+
+    let split_id = format!("{:08x}", rand::random::<u32>());
+
+    let shares = shamir_split(receiver_data.as_bytes(), threshold, total)?;
+
+    Ok(shares
+        .into_iter()
+        .map(|(index, data)| {
+            format!(
+                "{} {} {} {}/{} {}",
+                SPLIT_SHARE_PREFIX,
+                split_id,
+                threshold,
+                index,
+                total,
+                STANDARD.encode(data)
+            )
+        })
+        .collect())
+}
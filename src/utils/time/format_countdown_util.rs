@@ -0,0 +1,63 @@
+use chrono::{DateTime, Utc};
+
+use crate::types::Clock;
+
+// two most significant units left until the given time (e.g. "2d 4h", "4h 10m", "10m 5s"),
+// None once it has passed
+pub fn format_countdown(until: DateTime<Utc>, clock: &impl Clock) -> Option<String> {
+    let remaining = (until - clock.now()).num_seconds();
+
+    if remaining <= 0 {
+        return None;
+    }
+
+    let days = remaining / 86400;
+    let hours = remaining % 86400 / 3600;
+    let minutes = remaining % 3600 / 60;
+    let seconds = remaining % 60;
+
+    Some(if days > 0 {
+        format!("{days}d {hours}h")
+    } else if hours > 0 {
+        format!("{hours}h {minutes}m")
+    } else if minutes > 0 {
+        format!("{minutes}m {seconds}s")
+    } else {
+        format!("{seconds}s")
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, TimeDelta, Utc};
+
+    use super::format_countdown;
+
+    fn clock() -> DateTime<Utc> {
+        DateTime::from_timestamp(1_700_000_000, 0).unwrap()
+    }
+
+    fn countdown(seconds: i64) -> Option<String> {
+        format_countdown(clock() + TimeDelta::seconds(seconds), &clock())
+    }
+
+    #[test]
+    fn keeps_the_two_most_significant_units() {
+        assert_eq!(
+            countdown(2 * 86400 + 4 * 3600 + 59),
+            Some("2d 4h".to_string())
+        );
+        assert_eq!(
+            countdown(4 * 3600 + 10 * 60 + 5),
+            Some("4h 10m".to_string())
+        );
+        assert_eq!(countdown(10 * 60 + 5), Some("10m 5s".to_string()));
+        assert_eq!(countdown(5), Some("5s".to_string()));
+    }
+
+    #[test]
+    fn is_none_once_passed() {
+        assert_eq!(countdown(0), None);
+        assert_eq!(countdown(-60), None);
+    }
+}
@@ -0,0 +1,13 @@
+use crate::types::Clock;
+
+// e.g. "just now", "5m ago", "3h ago", "2d ago"
+pub fn format_time_ago(unix_time: i64, clock: &impl Clock) -> String {
+    let elapsed = (clock.now().timestamp() - unix_time).max(0);
+
+    match elapsed {
+        0..60 => "just now".to_string(),
+        60..3600 => format!("{}m ago", elapsed / 60),
+        3600..86400 => format!("{}h ago", elapsed / 3600),
+        _ => format!("{}d ago", elapsed / 86400),
+    }
+}
@@ -0,0 +1,15 @@
+use chrono::{DateTime, Local};
+
+pub fn format_unix_time(unix_time: i64) -> String {
+    let Some(date_time) = DateTime::from_timestamp(unix_time, 0) else {
+        return String::new();
+    };
+
+    let date_time = date_time.with_timezone(&Local);
+
+    if date_time.date_naive() == Local::now().date_naive() {
+        date_time.format("%H:%M:%S").to_string()
+    } else {
+        date_time.format("%b %d, %H:%M").to_string()
+    }
+}
@@ -0,0 +1,3 @@
+pub fn get_current_unix_time_millis() -> i64 {
+    chrono::Utc::now().timestamp_millis()
+}
@@ -0,0 +1,84 @@
+use chrono::{DateTime, TimeDelta, Utc};
+
+use crate::types::SenderMeta;
+
+use super::time_to_seconds;
+
+// time each key unlocks if no further ping succeeds, by key index,
+// counting from the creation for keys never pinged and None when unknown
+pub fn get_key_unlock_times(meta: &SenderMeta, total_keys: usize) -> Vec<Option<DateTime<Utc>>> {
+    let Some(timelock) = meta.timelock.clone().map(time_to_seconds) else {
+        return vec![None; total_keys];
+    };
+
+    (0..total_keys)
+        .map(|key_index| {
+            let last_ping = meta
+                .last_pings
+                .get(key_index)
+                .copied()
+                .flatten()
+                .or(meta.created_at)?;
+
+            DateTime::from_timestamp(last_ping, 0)?
+                .checked_add_signed(TimeDelta::try_seconds(timelock as i64)?)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::DateTime;
+
+    use crate::types::{SenderMeta, Time, TimeUnit};
+
+    use super::get_key_unlock_times;
+
+    const CREATED_AT: i64 = 1_700_000_000;
+
+    fn meta(last_pings: Vec<Option<i64>>) -> SenderMeta {
+        SenderMeta {
+            timelock: Some(Time {
+                magnitude: 2,
+                unit: TimeUnit::Hour,
+            }),
+            created_at: Some(CREATED_AT),
+            last_pings,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn counts_from_the_last_ping_or_the_creation() {
+        let unlock_times = get_key_unlock_times(&meta(vec![Some(CREATED_AT + 600), None]), 3);
+
+        assert_eq!(
+            unlock_times,
+            vec![
+                DateTime::from_timestamp(CREATED_AT + 600 + 7200, 0),
+                DateTime::from_timestamp(CREATED_AT + 7200, 0),
+                DateTime::from_timestamp(CREATED_AT + 7200, 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn is_unknown_without_timelock_or_start() {
+        let without_timelock = SenderMeta {
+            timelock: None,
+            ..meta(vec![Some(CREATED_AT)])
+        };
+
+        assert_eq!(get_key_unlock_times(&without_timelock, 2), vec![None, None]);
+
+        let without_start = SenderMeta {
+            created_at: None,
+            ..meta(vec![Some(CREATED_AT)])
+        };
+
+        assert_eq!(
+            get_key_unlock_times(&without_start, 2),
+            vec![DateTime::from_timestamp(CREATED_AT + 7200, 0), None]
+        );
+    }
+}
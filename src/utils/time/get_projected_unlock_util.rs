@@ -0,0 +1,50 @@
+use chrono::{DateTime, Utc};
+
+use wingedcap::client::KeyIndexArray;
+
+// a set unlocks once its last key does, the secret once its first set does
+pub fn get_projected_unlock(
+    key_unlock_times: &[Option<DateTime<Utc>>],
+    sets: &[KeyIndexArray],
+) -> Option<DateTime<Utc>> {
+    sets.iter()
+        .map(|set| {
+            set.iter()
+                .map(|key_index| key_unlock_times.get(*key_index as usize).copied().flatten())
+                .collect::<Option<Vec<_>>>()?
+                .into_iter()
+                .max()
+        })
+        .collect::<Option<Vec<_>>>()?
+        .into_iter()
+        .min()
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::DateTime;
+
+    use super::get_projected_unlock;
+
+    #[test]
+    fn waits_for_the_last_key_of_the_first_set() {
+        let unlock_times = [
+            DateTime::from_timestamp(100, 0),
+            DateTime::from_timestamp(300, 0),
+            DateTime::from_timestamp(200, 0),
+        ];
+
+        assert_eq!(
+            get_projected_unlock(&unlock_times, &[vec![0, 1], vec![0, 2]]),
+            DateTime::from_timestamp(200, 0)
+        );
+    }
+
+    #[test]
+    fn is_unknown_when_a_key_is() {
+        let unlock_times = [DateTime::from_timestamp(100, 0), None];
+
+        assert_eq!(get_projected_unlock(&unlock_times, &[vec![0, 1]]), None);
+        assert_eq!(get_projected_unlock(&unlock_times, &[vec![0, 2]]), None);
+    }
+}
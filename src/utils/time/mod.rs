@@ -9,3 +9,29 @@ pub use get_time_unit_name_util::*;
 
 pub mod get_time_unit_from_name_util;
 pub use get_time_unit_from_name_util::*;
+
+pub mod format_unix_time_util;
+pub use format_unix_time_util::*;
+
+pub mod format_time_ago_util;
+pub use format_time_ago_util::*;
+
+pub mod format_countdown_util;
+pub use format_countdown_util::*;
+
+pub mod get_key_unlock_times_util;
+pub use get_key_unlock_times_util::*;
+
+pub mod get_projected_unlock_util;
+pub use get_projected_unlock_util::*;
+
+#[cfg(any(feature = "desktop", feature = "web", feature = "mobile"))]
+pub mod use_now_util;
+#[cfg(any(feature = "desktop", feature = "web", feature = "mobile"))]
+pub use use_now_util::*;
+
+pub mod parse_time_util;
+pub use parse_time_util::*;
+
+pub mod get_current_unix_time_millis_util;
+pub use get_current_unix_time_millis_util::*;
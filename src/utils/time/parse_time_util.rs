@@ -0,0 +1,26 @@
+use crate::types::{Time, TimeUnit};
+
+pub fn parse_time(time: &str) -> Result<Time, String> {
+    let time = time.trim();
+
+    let unit_start = time
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(time.len());
+
+    let (magnitude, unit) = time.split_at(unit_start);
+
+    let magnitude = magnitude
+        .parse::<u64>()
+        .map_err(|_| format!("Invalid time: {}", time))?;
+
+    let unit = match unit.trim() {
+        "" | "s" | "sec" | "seconds" => TimeUnit::Second,
+        "m" | "min" | "minutes" => TimeUnit::Minute,
+        "h" | "hours" => TimeUnit::Hour,
+        "d" | "days" => TimeUnit::Day,
+        "w" | "weeks" => TimeUnit::Week,
+        unit => return Err(format!("Invalid time unit: {}", unit)),
+    };
+
+    Ok(Time { magnitude, unit })
+}
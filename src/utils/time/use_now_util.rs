@@ -0,0 +1,21 @@
+use chrono::{DateTime, Utc};
+
+use dioxus::prelude::*;
+
+use crate::types::{Clock, SystemClock};
+
+use super::wait;
+
+// current time, refreshed every interval so countdowns keep moving
+pub fn use_now(interval_ms: u32) -> Signal<DateTime<Utc>> {
+    let mut now = use_signal(|| SystemClock.now());
+
+    use_future(move || async move {
+        loop {
+            wait(interval_ms).await;
+            now.set(SystemClock.now());
+        }
+    });
+
+    now
+}
@@ -0,0 +1,26 @@
+use base64::{engine::general_purpose::STANDARD, Engine};
+
+use crate::constants::PASSPHRASE_ENVELOPE_PREFIX;
+
+use super::{derive_vault_key, parse_kdf_params, vault_decrypt};
+
+pub fn decrypt_message_with_passphrase(envelope: &str, passphrase: &str) -> Result<String, String> {
+    let mut parts = envelope.split_whitespace();
+
+    if parts.next() != Some(PASSPHRASE_ENVELOPE_PREFIX) {
+        return Err("not a passphrase-protected message".to_string());
+    }
+
+    let (Some(kdf), Some(salt), Some(encrypted)) = (parts.next(), parts.next(), parts.next())
+    else {
+        return Err("passphrase-protected message is incomplete".to_string());
+    };
+
+    let kdf = parse_kdf_params(kdf)?;
+
+    let salt = STANDARD.decode(salt).map_err(|e| e.to_string())?;
+
+    let key = derive_vault_key(passphrase, &salt, &kdf)?;
+
+    vault_decrypt(&key, encrypted)
+}
@@ -0,0 +1,32 @@
+use argon2::{Algorithm, Argon2, Params, Version};
+
+use crate::constants::{MAX_KDF_M_COST, MAX_KDF_P_COST, MAX_KDF_T_COST};
+
+use crate::types::{KdfAlgorithm, KdfParams};
+
+pub fn derive_vault_key(
+    passphrase: &str,
+    salt: &[u8],
+    kdf: &KdfParams,
+) -> Result<[u8; 32], String> {
+    if kdf.m_cost > MAX_KDF_M_COST || kdf.t_cost > MAX_KDF_T_COST || kdf.p_cost > MAX_KDF_P_COST {
+        return Err("key derivation parameters are too costly".to_string());
+    }
+
+    let algorithm = match kdf.algorithm {
+        KdfAlgorithm::Argon2id => Algorithm::Argon2id,
+    };
+
+    let version = Version::try_from(kdf.version).map_err(|e| e.to_string())?;
+
+    let params =
+        Params::new(kdf.m_cost, kdf.t_cost, kdf.p_cost, Some(32)).map_err(|e| e.to_string())?;
+
+    let mut key = [0u8; 32];
+
+    Argon2::new(algorithm, version, params)
+        .hash_password_into(passphrase.as_bytes(), salt, &mut key)
+        .map_err(|e| e.to_string())?;
+
+    Ok(key)
+}
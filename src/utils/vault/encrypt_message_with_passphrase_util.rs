@@ -0,0 +1,24 @@
+use base64::{engine::general_purpose::STANDARD, Engine};
+
+use crate::constants::{DEFAULT_KDF_PARAMS, PASSPHRASE_ENVELOPE_PREFIX};
+
+use super::{derive_vault_key, format_kdf_params, vault_encrypt};
+
+// a second factor on top of the keys, the recipient gets the passphrase out of band
+pub fn encrypt_message_with_passphrase(message: &str, passphrase: &str) -> Result<String, String> {
+    if passphrase.is_empty() {
+        return Err("passphrase is empty".to_string());
+    }
+
+    let salt: [u8; 16] = rand::random();
+
+    let key = derive_vault_key(passphrase, &salt, &DEFAULT_KDF_PARAMS)?;
+
+    Ok(format!(
+        "{} {} {} {}",
+        PASSPHRASE_ENVELOPE_PREFIX,
+        format_kdf_params(&DEFAULT_KDF_PARAMS),
+        STANDARD.encode(salt),
+        vault_encrypt(&key, message)?
+    ))
+}
@@ -0,0 +1,13 @@
+use crate::types::{KdfAlgorithm, KdfParams};
+
+// compact form without spaces for text envelopes, e.g. "argon2id:v=19,m=19456,t=2,p=1"
+pub fn format_kdf_params(kdf: &KdfParams) -> String {
+    let algorithm = match kdf.algorithm {
+        KdfAlgorithm::Argon2id => "argon2id",
+    };
+
+    format!(
+        "{}:v={},m={},t={},p={}",
+        algorithm, kdf.version, kdf.m_cost, kdf.t_cost, kdf.p_cost
+    )
+}
@@ -0,0 +1,36 @@
+use crate::constants::{DEFAULT_KDF_PARAMS, PASSPHRASE_ENVELOPE_PREFIX};
+
+use super::format_kdf_params;
+
+// length of a message of `message_length` bytes once encrypted with a passphrase
+pub fn get_passphrase_envelope_length(message_length: usize) -> usize {
+    let base64_length = |bytes: usize| bytes.div_ceil(3) * 4;
+
+    // prefix, kdf, 16 bytes salt, 12 bytes nonce and 16 bytes tag around the message
+    PASSPHRASE_ENVELOPE_PREFIX.len()
+        + 1
+        + format_kdf_params(&DEFAULT_KDF_PARAMS).len()
+        + 1
+        + base64_length(16)
+        + 1
+        + base64_length(12 + message_length + 16)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::encrypt_message_with_passphrase;
+
+    use super::get_passphrase_envelope_length;
+
+    #[test]
+    fn matches_the_encrypted_message() {
+        for message in ["", "a", "a message of some length"] {
+            assert_eq!(
+                encrypt_message_with_passphrase(message, "passphrase")
+                    .unwrap()
+                    .len(),
+                get_passphrase_envelope_length(message.len())
+            );
+        }
+    }
+}
@@ -0,0 +1,23 @@
+pub mod derive_vault_key_util;
+pub use derive_vault_key_util::*;
+
+pub mod format_kdf_params_util;
+pub use format_kdf_params_util::*;
+
+pub mod parse_kdf_params_util;
+pub use parse_kdf_params_util::*;
+
+pub mod vault_encrypt_util;
+pub use vault_encrypt_util::*;
+
+pub mod vault_decrypt_util;
+pub use vault_decrypt_util::*;
+
+pub mod encrypt_message_with_passphrase_util;
+pub use encrypt_message_with_passphrase_util::*;
+
+pub mod decrypt_message_with_passphrase_util;
+pub use decrypt_message_with_passphrase_util::*;
+
+pub mod get_passphrase_envelope_length_util;
+pub use get_passphrase_envelope_length_util::*;
@@ -0,0 +1,62 @@
+use crate::types::{KdfAlgorithm, KdfParams};
+
+// reads params written by format_kdf_params
+pub fn parse_kdf_params(kdf: &str) -> Result<KdfParams, String> {
+    let invalid_kdf = || format!("invalid key derivation parameters \"{}\"", kdf);
+
+    let (algorithm, params) = kdf.split_once(':').ok_or_else(invalid_kdf)?;
+
+    let algorithm = match algorithm {
+        "argon2id" => KdfAlgorithm::Argon2id,
+        _ => return Err(format!("unsupported key derivation \"{}\"", algorithm)),
+    };
+
+    let mut values = [None; 4];
+
+    for param in params.split(',') {
+        let (name, value) = param.split_once('=').ok_or_else(invalid_kdf)?;
+
+        let position = ["v", "m", "t", "p"]
+            .iter()
+            .position(|known| *known == name)
+            .ok_or_else(invalid_kdf)?;
+
+        values[position] = Some(value.parse::<u32>().map_err(|_| invalid_kdf())?);
+    }
+
+    let [Some(version), Some(m_cost), Some(t_cost), Some(p_cost)] = values else {
+        return Err(invalid_kdf());
+    };
+
+    Ok(KdfParams {
+        algorithm,
+        version,
+        m_cost,
+        t_cost,
+        p_cost,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::constants::DEFAULT_KDF_PARAMS;
+
+    use crate::utils::format_kdf_params;
+
+    use super::parse_kdf_params;
+
+    #[test]
+    fn reads_formatted_params_back() {
+        assert_eq!(
+            parse_kdf_params(&format_kdf_params(&DEFAULT_KDF_PARAMS)),
+            Ok(DEFAULT_KDF_PARAMS)
+        );
+    }
+
+    #[test]
+    fn rejects_incomplete_or_unknown_params() {
+        assert!(parse_kdf_params("argon2id:v=19,m=19456,t=2").is_err());
+        assert!(parse_kdf_params("scrypt:v=19,m=19456,t=2,p=1").is_err());
+        assert!(parse_kdf_params("argon2id:v=19,m=19456,t=2,p=1,x=3").is_err());
+    }
+}
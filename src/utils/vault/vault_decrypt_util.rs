@@ -0,0 +1,21 @@
+use aes_gcm::{aead::Aead, Aes256Gcm, KeyInit, Nonce};
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+
+pub fn vault_decrypt(key: &[u8; 32], encrypted: &str) -> Result<String, String> {
+    let cipher = Aes256Gcm::new_from_slice(key).map_err(|e| e.to_string())?;
+
+    let bytes = STANDARD.decode(encrypted).map_err(|e| e.to_string())?;
+
+    if bytes.len() < 12 {
+        return Err("encrypted data is too short".to_string());
+    }
+
+    let (nonce, ciphertext) = bytes.split_at(12);
+
+    let plaintext = cipher
+        .decrypt(Nonce::from_slice(nonce), ciphertext)
+        .map_err(|_| "failed to decrypt, wrong passphrase or corrupted data".to_string())?;
+
+    String::from_utf8(plaintext).map_err(|e| e.to_string())
+}
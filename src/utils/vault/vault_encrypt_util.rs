@@ -0,0 +1,15 @@
+use aes_gcm::{aead::Aead, Aes256Gcm, KeyInit, Nonce};
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+
+pub fn vault_encrypt(key: &[u8; 32], plaintext: &str) -> Result<String, String> {
+    let cipher = Aes256Gcm::new_from_slice(key).map_err(|e| e.to_string())?;
+
+    let nonce: [u8; 12] = rand::random();
+
+    let ciphertext = cipher
+        .encrypt(Nonce::from_slice(&nonce), plaintext.as_bytes())
+        .map_err(|e| e.to_string())?;
+
+    Ok(STANDARD.encode([nonce.as_slice(), ciphertext.as_slice()].concat()))
+}
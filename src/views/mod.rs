@@ -3,3 +3,9 @@ pub use sender_view::*;
 
 pub mod receiver_view;
 pub use receiver_view::*;
+
+pub mod settings_view;
+pub use settings_view::*;
+
+pub mod unlock_view;
+pub use unlock_view::*;
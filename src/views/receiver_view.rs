@@ -1,67 +1,141 @@
 use dioxus::prelude::*;
 
-use wingedcap::client::ReceiverStored;
+use wingedcap::client::{Key, Receiver, ReceiverStored};
 
-use cross_clipboard::{copy_to_clipboard, paste_from_clipboard};
+use cross_clipboard::paste_from_clipboard;
 use cross_storage::storage_del;
 
-use crate::storage::{get_receivers, store_receiver};
+use crate::storage::{
+    find_receiver_conflict, get_receivers, relabel_receiver, remove_inbox_entry,
+    remove_receiver_meta, store_receiver, store_receiver_with_resolution,
+};
+
+use crate::types::{ConflictResolution, LoadedRecords, RecordFailure};
+
+use crate::utils::{copy_secret_to_clipboard, is_receiver_share, is_split_share};
 
 use lucide_dioxus::{ClipboardList, Plus, Trash2};
 
 use crate::ui::button::{Button, ButtonVariant};
 use crate::ui::card::{Card, CardContent, CardDescription, CardHeader, CardTitle};
 
-use crate::components::{CreateReceiverDialog, ReceiverSecret};
+use crate::components::{
+    CreateReceiverDialog, ReceiverConflictDialog, ReceiverSecret, ShareReceiverDataDialog,
+    UnreadableRecord,
+};
 
 #[component]
 pub fn ReceiverView() -> Element {
     let mut is_create_receiver_dialog_open = use_signal(|| false);
+    // found in the clipboard but needs more from the user, completed in the create dialog
+    let mut pending_data: Signal<Option<String>> = use_signal(|| None);
+
+    let mut sharing_receiver: Signal<Option<ReceiverStored>> = use_signal(|| None);
 
     let mut stored_receivers: Signal<Option<Vec<(String, ReceiverStored)>>> = use_signal(|| None);
+    let mut unreadable_receivers: Signal<Vec<RecordFailure>> = use_signal(Vec::new);
+
+    let mut receiver_conflict: Signal<Option<(ReceiverStored, ReceiverStored)>> =
+        use_signal(|| None);
+
+    let mut refetch = move || match get_receivers() {
+        Ok(LoadedRecords { records, failures }) => {
+            stored_receivers.set(Some(records));
+            unreadable_receivers.set(failures);
+        }
 
-    let mut refetch = move || {
-        if let Ok(receivers) = get_receivers() {
-            stored_receivers.set(Some(receivers));
+        Err(e) => {
+            tracing::error!("error loading receivers: {:?}", e);
         }
     };
 
+    let mut handle_remove_unreadable = move |storage_id: &str| {
+        let _ = storage_del(storage_id);
+
+        refetch();
+    };
+
     use_effect(move || {
         refetch();
     });
 
+    let mut import_receiver = move |receiver: ReceiverStored| {
+        match find_receiver_conflict(&receiver) {
+            Ok(Some((_, existing))) => {
+                receiver_conflict.set(Some((receiver, existing)));
+            }
+
+            Ok(None) => {
+                if let Err(e) = store_receiver(receiver) {
+                    tracing::error!("error storing receiver: {:?}", e);
+                }
+            }
+
+            Err(e) => {
+                tracing::error!("error checking receiver conflicts: {:?}", e);
+            }
+        }
+
+        refetch();
+    };
+
     let handle_create = move |receiver_to_store: ReceiverStored| {
-        let _ = store_receiver(receiver_to_store);
+        import_receiver(receiver_to_store);
 
         is_create_receiver_dialog_open.set(false);
+        pending_data.set(None);
+    };
+
+    let handle_resolve = move |(receiver, resolution): (ReceiverStored, ConflictResolution)| {
+        if let Err(e) = store_receiver_with_resolution(receiver, resolution) {
+            tracing::error!("error storing receiver: {:?}", e);
+        }
+
+        receiver_conflict.set(None);
 
         refetch();
     };
 
-    let handle_relabel = move |_storage_id: &str, _label: String| {};
+    let mut handle_relabel = move |storage_id: &str, label: String| {
+        let receiver = stored_receivers()
+            .unwrap_or_default()
+            .into_iter()
+            .find(|(id, _)| id == storage_id);
 
-    let handle_copy = move |receiver: &ReceiverStored| {
-        let label = receiver.label.clone();
-        let keys = receiver.keys.clone();
-        let sets = receiver.sets.clone();
-
-        let receiver = ReceiverStored { label, keys, sets };
+        if let Some((_, receiver)) = receiver {
+            match relabel_receiver(storage_id, receiver, label) {
+                Ok(_) => {
+                    refetch();
+                }
 
-        if let Ok(str) = serde_json::to_string(&receiver) {
-            let _ = copy_to_clipboard(&str);
+                Err(e) => {
+                    tracing::error!("error updating receiver in storage: {:?}", e);
+                }
+            }
         }
     };
 
+    let mut handle_copy = move |receiver: &ReceiverStored| {
+        sharing_receiver.set(Some(receiver.clone()));
+    };
+
     let handle_upload = move || async move {
         let paste_result = paste_from_clipboard().await;
 
         match paste_result {
+            Ok(receiver_content)
+                if is_receiver_share(&receiver_content) || is_split_share(&receiver_content) =>
+            {
+                pending_data.set(Some(receiver_content));
+                is_create_receiver_dialog_open.set(true);
+            }
+
             Ok(receiver_content) => {
                 let parsing_result = serde_json::from_str::<ReceiverStored>(&receiver_content);
 
                 match parsing_result {
                     Ok(receiver) => {
-                        let _ = store_receiver(receiver);
+                        import_receiver(receiver);
                     }
 
                     Err(e) => {
@@ -77,9 +151,13 @@ pub fn ReceiverView() -> Element {
         refetch();
     };
 
-    let mut handle_remove = move |storage_id: &str| {
+    let mut handle_remove = move |storage_id: &str, receiver: &ReceiverStored| {
         let _ = storage_del(storage_id);
 
+        let _ = remove_receiver_meta(receiver);
+
+        let _ = remove_inbox_entry(receiver);
+
         refetch();
     };
 
@@ -87,8 +165,8 @@ pub fn ReceiverView() -> Element {
         let current_receivers = stored_receivers.read().clone();
 
         if let Some(current_receivers) = current_receivers {
-            for (id, _) in current_receivers {
-                handle_remove(&id);
+            for (id, receiver) in current_receivers {
+                handle_remove(&id, &receiver);
             }
         }
     };
@@ -140,19 +218,28 @@ pub fn ReceiverView() -> Element {
                     for (_ , (storage_id , stored_receiver)) in stored_receivers.iter().map(|s| s.clone()).enumerate() {
                         {
                             let id = storage_id.clone();
+                            let receiver_to_remove = stored_receiver.clone();
 
                             rsx! {
                                 ReceiverSecret {
                                     receiver: stored_receiver.clone(),
                                     on_relabel: move |label| handle_relabel(&storage_id, label),
                                     on_copy: move |_| handle_copy(&stored_receiver),
-                                    on_remove: move |_| handle_remove(&id),
+                                    on_remove: move |_| handle_remove(&id, &receiver_to_remove),
                                 }
                             }
                         }
                     }
 
-                    if stored_receivers.is_empty() {
+                    for failure in unreadable_receivers() {
+                        UnreadableRecord {
+                            key: "{failure.storage_id}",
+                            failure: failure.clone(),
+                            on_remove: move |_| handle_remove_unreadable(&failure.storage_id),
+                        }
+                    }
+
+                    if stored_receivers.is_empty() && unreadable_receivers.read().is_empty() {
                         div { class: "rounded-lg border border-primary/40 bg-primary/5 px-5 py-4 text-sm text-primary animate-fade-in",
                             p { class: "font-semibold mb-3 text-base",
                                 "No secrets to receive yet? Let's fix it!"
@@ -174,8 +261,48 @@ pub fn ReceiverView() -> Element {
 
         CreateReceiverDialog {
             on_submit: handle_create,
+            pending_data: pending_data(),
             open: is_create_receiver_dialog_open(),
-            on_open_change: move |open| is_create_receiver_dialog_open.set(open),
+            on_open_change: move |open: bool| {
+                is_create_receiver_dialog_open.set(open);
+
+                if !open {
+                    pending_data.set(None);
+                }
+            },
+        }
+
+        ShareReceiverDataDialog {
+            on_copy: move |receiver_data: String| {
+                let _ = copy_secret_to_clipboard(&receiver_data);
+            },
+            receiver: sharing_receiver()
+                .map(|receiver| Receiver {
+                    keys: receiver
+                        .keys
+                        .into_iter()
+                        .map(|key| Key {
+                            host: key.host,
+                            pk: key.pk,
+                            id: key.id,
+                        })
+                        .collect(),
+                    sets: receiver.sets,
+                }),
+            label: sharing_receiver().map(|receiver| receiver.label),
+            is_reshare: true,
+            open: sharing_receiver().is_some(),
+            on_open_change: move |open: bool| {
+                if !open {
+                    sharing_receiver.set(None);
+                }
+            },
+        }
+
+        ReceiverConflictDialog {
+            conflict: receiver_conflict(),
+            on_resolve: handle_resolve,
+            on_cancel: move |_| receiver_conflict.set(None),
         }
     }
 }
@@ -1,24 +1,35 @@
+use std::collections::HashMap;
+
 use dioxus::prelude::*;
 
-use wingedcap::client::{
-    generate_standard_vault_sets, KeyWithMeta, Receiver, SenderStored, Server, ServerWithMeta,
+use wingedcap::{
+    client::{generate_standard_vault_sets, KeyIndexArray, Receiver, SenderStored, ServerWithMeta},
+    get_current_unix_time,
 };
 
-use crate::types::Time;
+use crate::types::{
+    LoadedRecords, RecordFailure, SenderBundle, SenderMeta, SetKeyOutcome, SetSecretError,
+    Settings, ShareStatus, Time,
+};
 
-use cross_clipboard::{copy_to_clipboard, paste_from_clipboard};
-use cross_storage::{storage_del, storage_set};
+use cross_clipboard::paste_from_clipboard;
+use cross_storage::storage_del;
 
-use crate::storage::{get_senders, store_sender};
+use crate::storage::{
+    get_sender_bundle, get_senders, remove_sender_bundle, remove_sender_meta,
+    set_sender_share_status, store_sender, store_sender_bundle, store_sender_meta,
+};
 
-use crate::utils::{set_secret, time_to_seconds};
+use crate::utils::{copy_secret_to_clipboard, create_secret};
 
 use lucide_dioxus::{ClipboardList, Plus, Trash2};
 
 use crate::ui::button::{Button, ButtonVariant};
 use crate::ui::card::{Card, CardContent, CardDescription, CardHeader, CardTitle};
 
-use crate::components::{CreateSecretFormDialog, SenderSecret, ShareReceiverDataDialog};
+use crate::components::{
+    CreateSecretFormDialog, SenderSecret, ShareReceiverDataDialog, UnreadableRecord,
+};
 
 #[derive(Clone, Debug)]
 pub struct CreateSecretFormData {
@@ -27,23 +38,60 @@ pub struct CreateSecretFormData {
     pub timelock: Time,
     pub servers: Vec<ServerWithMeta>,
     pub required_keys: u64,
+    // overrides the standard "required keys of all keys" sets when given
+    pub custom_sets: Option<Vec<KeyIndexArray>>,
 }
 
 #[component]
 pub fn SenderView() -> Element {
     let mut new_secret_receiver: Signal<Option<Receiver>> = use_signal(|| None);
+    // the sender whose receiver data is being shared, created or stored earlier
+    let mut sharing_sender: Signal<Option<SenderStored>> = use_signal(|| None);
+    let mut is_resharing = use_signal(|| false);
+    // servers that failed while creating the last secret, which was created without their keys
+    let mut skipped_keys: Signal<Vec<SetKeyOutcome>> = use_signal(Vec::new);
+
+    let settings = use_context::<Signal<Settings>>();
 
     let mut is_create_dialog_open = use_signal(|| false);
     let mut is_share_receiver_data_dialog_open = use_signal(|| false);
 
     let mut stored_senders: Signal<Option<Vec<(String, SenderStored)>>> = use_signal(|| None);
+    let mut unreadable_senders: Signal<Vec<RecordFailure>> = use_signal(Vec::new);
+    let mut share_statuses: Signal<HashMap<String, ShareStatus>> = use_signal(HashMap::new);
 
-    let mut refetch = move || {
-        if let Ok(senders) = get_senders() {
-            stored_senders.set(Some(senders));
+    let mut refetch = move || match get_senders() {
+        Ok(LoadedRecords { records, failures }) => {
+            share_statuses.set(
+                records
+                    .iter()
+                    .filter_map(|(storage_id, sender)| match get_sender_bundle(sender) {
+                        Ok(bundle) => {
+                            bundle.map(|bundle| (storage_id.clone(), bundle.share_status))
+                        }
+                        Err(e) => {
+                            tracing::error!("error loading receiver data: {:?}", e);
+                            None
+                        }
+                    })
+                    .collect(),
+            );
+
+            stored_senders.set(Some(records));
+            unreadable_senders.set(failures);
+        }
+
+        Err(e) => {
+            tracing::error!("error loading senders: {:?}", e);
         }
     };
 
+    let mut handle_remove_unreadable = move |storage_id: &str| {
+        let _ = storage_del(storage_id);
+
+        refetch();
+    };
+
     use_effect(move || {
         refetch();
     });
@@ -51,6 +99,9 @@ pub fn SenderView() -> Element {
     use_effect(move || {
         if !is_share_receiver_data_dialog_open() {
             new_secret_receiver.set(None);
+            sharing_sender.set(None);
+            is_resharing.set(false);
+            skipped_keys.set(vec![]);
         }
     });
 
@@ -58,48 +109,57 @@ pub fn SenderView() -> Element {
                                   label,
                                   message,
                                   timelock,
-                                  servers: servers_with_meta,
+                                  servers,
                                   required_keys,
+                                  custom_sets,
                               }: CreateSecretFormData| async move {
-        let timelock = time_to_seconds(timelock);
+        let options = settings.peek().key_creation.clone();
 
-        let servers: Vec<Server> = servers_with_meta
-            .iter()
-            .map(|ServerWithMeta { host, pk, .. }| Server {
-                host: host.clone(),
-                pk: pk.clone(),
-            })
-            .collect();
+        let sets = custom_sets
+            .unwrap_or_else(|| generate_standard_vault_sets(servers.len() as u64, required_keys));
 
-        let sets = generate_standard_vault_sets(servers.len() as u64, required_keys);
+        let sender_meta = SenderMeta {
+            timelock: Some(timelock.clone()),
+            created_at: Some(get_current_unix_time()),
+            required_keys: sets.iter().map(|set| set.len() as u64).min(),
+            ..Default::default()
+        };
 
-        let new_secret_result = set_secret(message, timelock, servers, sets).await;
+        let new_secret_result =
+            create_secret(label, message, timelock, servers, sets, &options).await;
 
         match new_secret_result {
-            Ok((sender, receiver)) => {
-                let keys_with_meta = sender
-                    .keys
-                    .iter()
-                    .enumerate()
-                    .map(|(index, key)| {
-                        let ServerWithMeta { meta, .. } = servers_with_meta[index].clone();
-
-                        KeyWithMeta {
-                            host: key.host.clone(),
-                            pk: key.pk.clone(),
-                            id: key.id.clone(),
-                            meta: meta.clone(),
-                        }
-                    })
-                    .collect();
-
-                let sender_to_store = SenderStored {
-                    label: label.clone(),
-                    keys: keys_with_meta,
-                    sets: sender.sets.clone(),
+            Ok((sender_to_store, receiver, outcomes)) => {
+                let bundle = SenderBundle {
+                    receiver: receiver.clone(),
+                    share_status: ShareStatus::NotShared,
                 };
 
-                let _ = store_sender(sender_to_store);
+                // the sender goes last, it is only listed once everything it needs is stored
+                let store_result = store_sender_meta(&sender_to_store, sender_meta)
+                    .and_then(|_| store_sender_bundle(&sender_to_store, bundle))
+                    .and_then(|_| store_sender(sender_to_store.clone()));
+
+                if let Err(e) = store_result {
+                    tracing::error!("error storing sender: {:?}", e);
+
+                    let _ = remove_sender_meta(&sender_to_store);
+                    let _ = remove_sender_bundle(&sender_to_store);
+
+                    return Err(SetSecretError {
+                        message: format!("the secret could not be saved on this device: {}", e),
+                        outcomes,
+                    });
+                }
+
+                skipped_keys.set(
+                    outcomes
+                        .into_iter()
+                        .filter(|outcome| outcome.error.is_some())
+                        .collect(),
+                );
+
+                sharing_sender.set(Some(sender_to_store.clone()));
 
                 is_create_dialog_open.set(false);
 
@@ -113,57 +173,90 @@ pub fn SenderView() -> Element {
             }
 
             Err(e) => {
-                let error = format!("error creating sender: {:?}", e);
-                tracing::error!(error);
-                Err(error)
+                tracing::error!("error creating sender: {}", e);
+                Err(e)
             }
         }
     };
 
     let mut handle_relabel = move |storage_id: &str, label: String| {
-        let label_clone = label.clone();
+        let sender = stored_senders()
+            .unwrap_or_default()
+            .into_iter()
+            .find(|(id, _)| id == storage_id);
+
+        if let Some((_, sender)) = sender {
+            match store_sender(SenderStored { label, ..sender }) {
+                Ok(_) => {
+                    refetch();
+                }
 
-        if let Some(stored_senders) = stored_senders() {
-            for (id, sender) in stored_senders {
-                let label = label_clone.clone();
+                Err(e) => {
+                    tracing::error!("error updating sender in storage: {:?}", e);
+                }
+            }
+        }
+    };
 
-                if id == storage_id {
-                    let new_data = SenderStored { label, ..sender };
+    // a confirmed share stays confirmed when shared again
+    let mut handle_mark_shared = move || {
+        let Some(sender) = sharing_sender() else {
+            return;
+        };
+
+        let is_confirmed = matches!(
+            get_sender_bundle(&sender),
+            Ok(Some(SenderBundle {
+                share_status: ShareStatus::Confirmed,
+                ..
+            }))
+        );
+
+        if !is_confirmed {
+            if let Err(e) = set_sender_share_status(&sender, ShareStatus::Copied) {
+                tracing::error!("error updating share status: {:?}", e);
+            }
 
-                    match serde_json::to_string(&new_data) {
-                        Ok(new_data_json) => match storage_set(&storage_id, &new_data_json) {
-                            Ok(_) => {
-                                refetch();
-                            }
+            refetch();
+        }
+    };
 
-                            Err(e) => {
-                                tracing::error!("error updating sender in storage: {:?}", e);
-                            }
-                        },
-                        Err(e) => {
-                            tracing::error!("error serializing sender: {:?}", e);
-                        }
-                    }
+    let handle_copy_receiver = move |receiver_data: String| {
+        let _ = copy_secret_to_clipboard(&receiver_data);
 
-                    break;
-                }
-            }
-        }
+        is_create_dialog_open.set(false);
+
+        handle_mark_shared();
     };
 
-    let handle_copy_receiver = move || {
-        let receiver = new_secret_receiver.read().clone();
+    let mut handle_share_again = move |sender: &SenderStored| match get_sender_bundle(sender) {
+        Ok(Some(bundle)) => {
+            sharing_sender.set(Some(sender.clone()));
+            new_secret_receiver.set(Some(bundle.receiver));
+            is_resharing.set(true);
+            is_share_receiver_data_dialog_open.set(true);
+        }
 
-        if let Ok(str) = serde_json::to_string_pretty(&receiver) {
-            let _ = copy_to_clipboard(&str);
+        Ok(None) => {
+            tracing::error!("no receiver data stored for sender {}", sender.label);
+        }
+
+        Err(e) => {
+            tracing::error!("error loading receiver data: {:?}", e);
+        }
+    };
 
-            is_create_dialog_open.set(false);
+    let mut handle_confirm_receiver = move |sender: &SenderStored| {
+        if let Err(e) = set_sender_share_status(sender, ShareStatus::Confirmed) {
+            tracing::error!("error updating share status: {:?}", e);
         }
+
+        refetch();
     };
 
     let handle_copy = move |sender: &SenderStored| {
         if let Ok(str) = serde_json::to_string(&sender) {
-            let _ = copy_to_clipboard(&str);
+            let _ = copy_secret_to_clipboard(&str);
         }
     };
 
@@ -192,9 +285,13 @@ pub fn SenderView() -> Element {
         refetch();
     };
 
-    let mut handle_remove = move |storage_id: &str| {
+    let mut handle_remove = move |storage_id: &str, sender: &SenderStored| {
         let _ = storage_del(storage_id);
 
+        let _ = remove_sender_meta(sender);
+
+        let _ = remove_sender_bundle(sender);
+
         refetch();
     };
 
@@ -202,8 +299,8 @@ pub fn SenderView() -> Element {
         let current_senders = stored_senders.read().clone();
 
         if let Some(current_senders) = current_senders {
-            for (id, _) in current_senders {
-                handle_remove(&id);
+            for (id, sender) in current_senders {
+                handle_remove(&id, &sender);
             }
         }
     };
@@ -217,7 +314,7 @@ pub fn SenderView() -> Element {
 
                         div { class: "absolute font-normal right-2.5 flex gap-1",
                             CreateSecretFormDialog {
-                                on_submit: move |(data, callback): (CreateSecretFormData, Callback<Result<(), String>>)| async move {
+                                on_submit: move |(data, callback): (CreateSecretFormData, Callback<Result<(), SetSecretError>>)| async move {
                                     callback(handle_create(data).await);
                                 },
                                 open: is_create_dialog_open(),
@@ -253,19 +350,35 @@ pub fn SenderView() -> Element {
                     for (_ , (storage_id , stored_sender)) in stored_senders.iter().map(|s| s.clone()).enumerate() {
                         {
                             let id = storage_id.clone();
+                            let sender_to_copy = stored_sender.clone();
+                            let sender_to_share = stored_sender.clone();
+                            let sender_to_confirm = stored_sender.clone();
+                            let share_status = share_statuses.read().get(&storage_id).copied();
 
                             rsx! {
                                 SenderSecret {
+                                    key: "{storage_id}",
                                     sender: stored_sender.clone(),
                                     on_relabel: move |label| handle_relabel(&id, label),
-                                    on_copy: move |_| handle_copy(&stored_sender),
-                                    on_remove: move |_| handle_remove(&storage_id),
+                                    on_copy: move |_| handle_copy(&sender_to_copy),
+                                    on_remove: move |_| handle_remove(&storage_id, &stored_sender),
+                                    share_status,
+                                    on_share_receiver: move |_| handle_share_again(&sender_to_share),
+                                    on_confirm_receiver: move |_| handle_confirm_receiver(&sender_to_confirm),
                                 }
                             }
                         }
                     }
 
-                    if stored_senders.is_empty() {
+                    for failure in unreadable_senders() {
+                        UnreadableRecord {
+                            key: "{failure.storage_id}",
+                            failure: failure.clone(),
+                            on_remove: move |_| handle_remove_unreadable(&failure.storage_id),
+                        }
+                    }
+
+                    if stored_senders.is_empty() && unreadable_senders.read().is_empty() {
                         div { class: "rounded-lg border border-primary/40 bg-primary/5 px-5 py-4 text-sm text-primary animate-fade-in",
                             p { class: "font-semibold mb-3 text-base",
                                 "No secrets to send yet? Let's fix it!"
@@ -287,6 +400,10 @@ pub fn SenderView() -> Element {
 
         ShareReceiverDataDialog {
             on_copy: handle_copy_receiver,
+            on_show_qr_code: move |_| handle_mark_shared(),
+            receiver: new_secret_receiver(),
+            skipped_keys: skipped_keys(),
+            is_reshare: is_resharing(),
             open: is_share_receiver_data_dialog_open(),
             on_open_change: move |open| is_share_receiver_data_dialog_open.set(open),
         }
@@ -0,0 +1,895 @@
+use strum::IntoEnumIterator;
+
+use dioxus::prelude::*;
+
+use lucide_dioxus::{
+    ArchiveRestore, ClipboardX, Cog, DatabaseBackup, Download, HeartPulse, Lock, Network, Palette,
+    Plus, ShieldCheck, ShieldOff, Star, Trash2, Vault,
+};
+
+use crate::manager::{get_default_hub, get_hubs};
+
+use crate::storage::{
+    create_backup, disable_vault, enable_vault, get_settings, is_vault_enabled, lock_vault,
+    store_settings,
+};
+
+use crate::components::RestoreBackupDialog;
+
+use crate::types::{Hub, Settings, Theme, TimeUnit, VaultSession};
+
+use crate::utils::{get_time_unit_from_name, get_time_unit_name, save_file};
+
+use crate::ui::toast::{use_toast, ToastRenderer};
+
+use crate::ui::{
+    button::{Button, ButtonVariant},
+    card::{Card, CardContent, CardDescription, CardHeader, CardTitle},
+    input::Input,
+    input_animated_label::InputAnimatedLabel,
+    select::{Select, SelectContent, SelectItem, SelectTrigger, SelectValue},
+    separator::Separator,
+};
+
+const REQUIRED_KEYS_RATIO_OPTIONS: [(u64, &str); 5] = [
+    (0, "a single key"),
+    (25, "a quarter of the keys"),
+    (50, "half of the keys"),
+    (75, "three quarters of the keys"),
+    (100, "all the keys"),
+];
+
+const SET_KEY_RETRIES_OPTIONS: [u32; 5] = [0, 1, 2, 3, 5];
+
+const SET_KEY_BACKOFF_OPTIONS: [(u32, &str); 4] = [
+    (250, "250 ms"),
+    (500, "500 ms"),
+    (1000, "1 second"),
+    (2000, "2 seconds"),
+];
+
+const HEARTBEAT_FRACTION_OPTIONS: [u64; 5] = [10, 25, 50, 75, 90];
+
+const RECEIVER_POLL_INTERVAL_OPTIONS: [(u64, &str); 6] = [
+    (0, "never"),
+    (30, "every 30 seconds"),
+    (60, "every minute"),
+    (300, "every 5 minutes"),
+    (900, "every 15 minutes"),
+    (3600, "every hour"),
+];
+
+const UNLOCK_WARNING_OPTIONS: [(u64, &str); 5] = [
+    (604800, "1 week"),
+    (86400, "24 hours"),
+    (21600, "6 hours"),
+    (3600, "1 hour"),
+    (600, "10 minutes"),
+];
+
+const CLIPBOARD_CLEAR_DELAY_OPTIONS: [(u64, &str); 5] = [
+    (0, "never"),
+    (10, "after 10 seconds"),
+    (30, "after 30 seconds"),
+    (60, "after 1 minute"),
+    (300, "after 5 minutes"),
+];
+
+const VAULT_AUTO_LOCK_OPTIONS: [(u64, &str); 5] = [
+    (0, "never"),
+    (60, "after 1 minute"),
+    (300, "after 5 minutes"),
+    (900, "after 15 minutes"),
+    (3600, "after 1 hour"),
+];
+
+#[component]
+pub fn SettingsView() -> Element {
+    let mut toast = use_toast();
+
+    let mut settings = use_context::<Signal<Settings>>();
+
+    let empty_hub = Hub {
+        name: String::new(),
+        host: String::new(),
+        pk: String::new(),
+    };
+
+    let mut new_hub = use_signal(|| empty_hub.clone());
+
+    let VaultSession { mut is_locked, .. } = use_context::<VaultSession>();
+
+    let mut is_vault_on = use_signal(is_vault_enabled);
+
+    let mut new_passphrase = use_signal(String::new);
+    let mut confirm_passphrase = use_signal(String::new);
+
+    let mut backup_passphrase = use_signal(String::new);
+
+    // content of the backup file being restored
+    let mut restoring_backup_json: Signal<Option<String>> = use_signal(|| None);
+
+    let mut handle_change = move |new_settings: Settings| {
+        if new_settings == *settings.peek() {
+            return;
+        }
+
+        match store_settings(new_settings.clone()) {
+            Ok(_) => {
+                settings.set(new_settings);
+            }
+
+            Err(e) => {
+                tracing::error!("error storing settings: {:?}", e);
+                toast.error("Failed to save settings");
+            }
+        }
+    };
+
+    let mut handle_add_hub = move || {
+        let hub = new_hub.read().clone();
+
+        let mut new_settings = settings();
+
+        new_settings
+            .hubs
+            .hubs
+            .retain(|stored| stored.name != hub.name);
+        new_settings.hubs.hubs.push(hub);
+
+        handle_change(new_settings);
+
+        new_hub.set(empty_hub.clone());
+    };
+
+    let mut handle_remove_hub = move |name: String| {
+        let mut new_settings = settings();
+
+        new_settings.hubs.hubs.retain(|hub| hub.name != name);
+
+        if new_settings.hubs.default_hub == Some(name) {
+            new_settings.hubs.default_hub = None;
+        }
+
+        handle_change(new_settings);
+    };
+
+    let mut handle_set_default_hub = move |name: String| {
+        let mut new_settings = settings();
+
+        new_settings.hubs.default_hub = Some(name);
+
+        handle_change(new_settings);
+    };
+
+    let mut handle_enable_vault = move || match enable_vault(&new_passphrase()) {
+        Ok(_) => {
+            new_passphrase.set(String::new());
+            confirm_passphrase.set(String::new());
+            is_vault_on.set(true);
+        }
+
+        Err(e) => {
+            tracing::error!("error enabling vault: {:?}", e);
+            toast.error(format!("Failed to enable the vault: {}", e));
+        }
+    };
+
+    let mut handle_disable_vault = move || match disable_vault() {
+        Ok(_) => {
+            is_vault_on.set(false);
+        }
+
+        Err(e) => {
+            tracing::error!("error disabling vault: {:?}", e);
+            toast.error(format!("Failed to disable the vault: {}", e));
+        }
+    };
+
+    let mut handle_lock_vault = move || {
+        lock_vault();
+        is_locked.set(true);
+    };
+
+    let handle_export_backup = move || async move {
+        let passphrase = backup_passphrase();
+
+        let backup = create_backup(Some(&passphrase))
+            .and_then(|backup| serde_json::to_string_pretty(&backup).map_err(|e| e.to_string()));
+
+        let file_name = format!(
+            "wingedcap-backup-{}.json",
+            chrono::Local::now().format("%Y-%m-%d")
+        );
+
+        match backup {
+            Ok(backup_json) => {
+                match save_file(&file_name, &backup_json, "application/json").await {
+                    Ok(_) => backup_passphrase.set(String::new()),
+
+                    Err(e) => {
+                        tracing::error!("error saving backup: {:?}", e);
+                        toast.error(format!("Failed to save the backup: {}", e));
+                    }
+                }
+            }
+
+            Err(e) => {
+                tracing::error!("error creating backup: {:?}", e);
+                toast.error(format!("Failed to create the backup: {}", e));
+            }
+        }
+    };
+
+    let handle_pick_backup = move |e: FormEvent| async move {
+        let Some(file) = e.files().into_iter().next() else {
+            return;
+        };
+
+        match file.read_string().await {
+            Ok(backup_json) => restoring_backup_json.set(Some(backup_json)),
+
+            Err(e) => {
+                tracing::error!("error reading backup file: {:?}", e);
+                toast.error("Failed to read the backup file");
+            }
+        }
+    };
+
+    let handle_restored = move |(restored, is_settings_restored): (usize, bool)| {
+        tracing::info!("restored {} records from backup", restored);
+
+        if is_settings_restored {
+            settings.set(get_settings().unwrap_or_default());
+        }
+
+        restoring_backup_json.set(None);
+    };
+
+    let is_new_passphrase_invalid =
+        new_passphrase.read().is_empty() || new_passphrase() != confirm_passphrase();
+
+    // read to re-render whenever the settings (and thus the hubs) change
+    let stored_hubs = settings().hubs.hubs;
+
+    let hubs = get_hubs();
+
+    let default_hub_name = get_default_hub().ok().map(|hub| hub.name);
+
+    let is_new_hub_invalid = new_hub.read().name.is_empty()
+        || new_hub.read().host.is_empty()
+        || new_hub.read().pk.is_empty();
+
+    let required_keys_ratio = (settings().default_required_keys_ratio * 100.0).round() as u64;
+
+    let heartbeat_fraction = (settings().heartbeat_fraction * 100.0).round() as u64;
+
+    rsx! {
+        Card { class: "animate-fade-in",
+            CardHeader { class: "mb-2",
+                CardTitle { "Settings" }
+                CardDescription { "stored on this device only" }
+            }
+
+            CardContent { class: "flex flex-col",
+                div { class: "relative mt-4 mb-5 flex items-center justify-center",
+                    Separator { class: "w-full" }
+
+                    div { class: "text-muted-foreground bg-background absolute flex items-center gap-2 px-4 text-sm font-semibold",
+                        span { class: "tracking-wider", "New secrets" }
+                        Cog { class: "" }
+                    }
+                }
+
+                div { class: "flex flex-col gap-4 text-sm",
+                    div { class: "flex items-center gap-2",
+                        span { class: "grow text-muted-foreground", "Default lock" }
+
+                        div { class: "relative flex items-center",
+                            InputAnimatedLabel {
+                                label: "Lock",
+                                value: "{settings().default_timelock.magnitude}",
+                                class: "w-42 pr-32 text-right",
+                                oninput: move |e: FormEvent| {
+                                    if let Ok(magnitude) = e.value().parse() {
+                                        let mut new_settings = settings();
+                                        new_settings.default_timelock.magnitude = magnitude;
+                                        handle_change(new_settings);
+                                    }
+                                },
+                            }
+
+                            Select {
+                                class: "absolute right-1 h-6 w-28",
+                                value: "{get_time_unit_name(settings().default_timelock.unit)}",
+                                on_value_change: move |value: String| {
+                                    if let Ok(unit) = get_time_unit_from_name(value) {
+                                        let mut new_settings = settings();
+                                        new_settings.default_timelock.unit = unit;
+                                        handle_change(new_settings);
+                                    }
+                                },
+
+                                SelectTrigger { SelectValue {} }
+                                SelectContent { class: "",
+                                    for unit in TimeUnit::iter().map(get_time_unit_name) {
+                                        SelectItem { value: "{unit}",
+                                            span { "{unit}" }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+
+                    div { class: "flex items-center gap-2",
+                        span { class: "grow text-muted-foreground", "Default required keys" }
+
+                        Select {
+                            class: "w-56",
+                            value: "{required_keys_ratio}",
+                            on_value_change: move |value: String| {
+                                if let Ok(percent) = value.parse::<u64>() {
+                                    let mut new_settings = settings();
+                                    new_settings.default_required_keys_ratio = percent as f64 / 100.0;
+                                    handle_change(new_settings);
+                                }
+                            },
+
+                            SelectTrigger {
+                                span { class: "grow text-left",
+                                    {
+                                        REQUIRED_KEYS_RATIO_OPTIONS
+                                            .iter()
+                                            .find(|(percent, _)| *percent == required_keys_ratio)
+                                            .map(|(_, name)| name.to_string())
+                                            .unwrap_or(format!("{required_keys_ratio}% of the keys"))
+                                    }
+                                }
+                            }
+                            SelectContent { class: "",
+                                for (percent , name) in REQUIRED_KEYS_RATIO_OPTIONS {
+                                    SelectItem { value: "{percent}",
+                                        span { "{name}" }
+                                    }
+                                }
+                            }
+                        }
+                    }
+
+                    div { class: "flex items-center gap-2",
+                        span { class: "grow text-muted-foreground", "Retries when a key fails" }
+
+                        Select {
+                            class: "w-28",
+                            value: "{settings().key_creation.retries}",
+                            on_value_change: move |value: String| {
+                                if let Ok(retries) = value.parse() {
+                                    let mut new_settings = settings();
+                                    new_settings.key_creation.retries = retries;
+                                    handle_change(new_settings);
+                                }
+                            },
+
+                            SelectTrigger { SelectValue {} }
+                            SelectContent { class: "",
+                                for retries in SET_KEY_RETRIES_OPTIONS {
+                                    SelectItem { value: "{retries}",
+                                        span { "{retries}" }
+                                    }
+                                }
+                            }
+                        }
+                    }
+
+                    div { class: "flex items-center gap-2",
+                        span { class: "grow text-muted-foreground", "Wait before the first retry" }
+
+                        Select {
+                            class: "w-28",
+                            value: "{settings().key_creation.backoff}",
+                            on_value_change: move |value: String| {
+                                if let Ok(backoff) = value.parse() {
+                                    let mut new_settings = settings();
+                                    new_settings.key_creation.backoff = backoff;
+                                    handle_change(new_settings);
+                                }
+                            },
+
+                            SelectTrigger {
+                                span { class: "grow text-left",
+                                    {
+                                        SET_KEY_BACKOFF_OPTIONS
+                                            .iter()
+                                            .find(|(backoff, _)| *backoff == settings().key_creation.backoff)
+                                            .map(|(_, name)| name.to_string())
+                                            .unwrap_or(format!("{} ms", settings().key_creation.backoff))
+                                    }
+                                }
+                            }
+                            SelectContent { class: "",
+                                for (backoff , name) in SET_KEY_BACKOFF_OPTIONS {
+                                    SelectItem { value: "{backoff}",
+                                        span { "{name}" }
+                                    }
+                                }
+                            }
+                        }
+                    }
+
+                    div { class: "flex items-center gap-2",
+                        span { class: "grow text-muted-foreground", "When some keys still fail" }
+
+                        Select {
+                            class: "w-56",
+                            value: "{settings().key_creation.allow_partial}",
+                            on_value_change: move |value: String| {
+                                if let Ok(allow_partial) = value.parse() {
+                                    let mut new_settings = settings();
+                                    new_settings.key_creation.allow_partial = allow_partial;
+                                    handle_change(new_settings);
+                                }
+                            },
+
+                            SelectTrigger {
+                                span { class: "grow text-left",
+                                    if settings().key_creation.allow_partial {
+                                        "keep the others if enough"
+                                    } else {
+                                        "cancel the secret"
+                                    }
+                                }
+                            }
+                            SelectContent { class: "",
+                                SelectItem { value: "false",
+                                    span { "cancel the secret" }
+                                }
+                                SelectItem { value: "true",
+                                    span { "keep the others if enough" }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                div { class: "relative mt-8 mb-5 flex items-center justify-center",
+                    Separator { class: "w-full" }
+
+                    div { class: "text-muted-foreground bg-background absolute flex items-center gap-2 px-4 text-sm font-semibold",
+                        span { class: "tracking-wider", "Heartbeat" }
+                        HeartPulse { class: "" }
+                    }
+                }
+
+                div { class: "flex items-center gap-2 text-sm",
+                    span { class: "grow text-muted-foreground",
+                        "Ping every sender secret after this share of its lock"
+                    }
+
+                    Select {
+                        class: "w-28",
+                        value: "{heartbeat_fraction}",
+                        on_value_change: move |value: String| {
+                            if let Ok(percent) = value.parse::<u64>() {
+                                let mut new_settings = settings();
+                                new_settings.heartbeat_fraction = percent as f64 / 100.0;
+                                handle_change(new_settings);
+                            }
+                        },
+
+                        SelectTrigger {
+                            span { class: "grow text-left", "{heartbeat_fraction}%" }
+                        }
+                        SelectContent { class: "",
+                            for percent in HEARTBEAT_FRACTION_OPTIONS {
+                                SelectItem { value: "{percent}",
+                                    span { "{percent}%" }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                div { class: "flex items-center gap-2 mt-4 text-sm",
+                    span { class: "grow text-muted-foreground",
+                        "Check receiver secrets for an unlock"
+                    }
+
+                    Select {
+                        class: "w-56",
+                        value: "{settings().receiver_poll_interval}",
+                        on_value_change: move |value: String| {
+                            if let Ok(seconds) = value.parse::<u64>() {
+                                let mut new_settings = settings();
+                                new_settings.receiver_poll_interval = seconds;
+                                handle_change(new_settings);
+                            }
+                        },
+
+                        SelectTrigger {
+                            span { class: "grow text-left",
+                                {
+                                    RECEIVER_POLL_INTERVAL_OPTIONS
+                                        .iter()
+                                        .find(|(seconds, _)| *seconds == settings().receiver_poll_interval)
+                                        .map(|(_, name)| name.to_string())
+                                        .unwrap_or(format!("every {} seconds", settings().receiver_poll_interval))
+                                }
+                            }
+                        }
+                        SelectContent { class: "",
+                            for (seconds , name) in RECEIVER_POLL_INTERVAL_OPTIONS {
+                                SelectItem { value: "{seconds}",
+                                    span { "{name}" }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                div { class: "flex flex-col gap-2 mt-4 text-sm",
+                    span { class: "text-muted-foreground",
+                        "Warn before a sender secret unlocks if it is not pinged"
+                    }
+
+                    div { class: "flex flex-wrap items-center gap-x-4 gap-y-2",
+                        for (seconds , name) in UNLOCK_WARNING_OPTIONS {
+                            label { class: "flex items-center gap-2 cursor-pointer",
+                                input {
+                                    r#type: "checkbox",
+                                    class: "size-4 accent-primary",
+                                    checked: settings().unlock_warnings.contains(&seconds),
+                                    onchange: move |e: FormEvent| {
+                                        let mut new_settings = settings();
+                                        new_settings.unlock_warnings.retain(|warning| *warning != seconds);
+                                        if e.checked() {
+                                            new_settings.unlock_warnings.push(seconds);
+                                            new_settings.unlock_warnings.sort_unstable_by(|a, b| b.cmp(a));
+                                        }
+                                        handle_change(new_settings);
+                                    },
+                                }
+                                span { "{name}" }
+                            }
+                        }
+                    }
+                }
+
+                div { class: "relative mt-8 mb-5 flex items-center justify-center",
+                    Separator { class: "w-full" }
+
+                    div { class: "text-muted-foreground bg-background absolute flex items-center gap-2 px-4 text-sm font-semibold",
+                        span { class: "tracking-wider", "Hubs" }
+                        Network { class: "" }
+                    }
+                }
+
+                div { class: "flex flex-col gap-2 text-sm",
+                    for hub in hubs {
+                        div { class: "flex items-center gap-2",
+                            div { class: "flex grow items-center gap-2 min-w-0 h-9 px-3 border border-border rounded-md",
+                                span { class: "font-medium shrink-0", "{hub.name}" }
+                                span { class: "truncate text-muted-foreground text-xs", "{hub.host}" }
+                            }
+
+                            Button {
+                                r#type: "button",
+                                variant: ButtonVariant::Ghost,
+                                class: "px-0 shrink-0",
+                                disabled: default_hub_name == Some(hub.name.clone()),
+                                onclick: {
+                                    let name = hub.name.clone();
+                                    move |_| handle_set_default_hub(name.clone())
+                                },
+                                if default_hub_name == Some(hub.name.clone()) {
+                                    Star { class: "fill-primary stroke-primary" }
+                                } else {
+                                    Star { class: "" }
+                                }
+                            }
+
+                            Button {
+                                r#type: "button",
+                                variant: ButtonVariant::Ghost,
+                                class: "px-0 shrink-0",
+                                disabled: !stored_hubs.iter().any(|stored| stored.name == hub.name),
+                                onclick: {
+                                    let name = hub.name.clone();
+                                    move |_| handle_remove_hub(name.clone())
+                                },
+                                Trash2 { class: "text-destructive" }
+                            }
+                        }
+                    }
+
+                    form {
+                        class: "flex items-center gap-2",
+                        onsubmit: move |e: FormEvent| {
+                            e.prevent_default();
+                            handle_add_hub();
+                        },
+
+                        div { class: "grid grow grid-cols-3 gap-2",
+                            Input {
+                                placeholder: "Name",
+                                value: new_hub.read().name.clone(),
+                                oninput: move |e: FormEvent| new_hub.with_mut(|hub| hub.name = e.value()),
+                            }
+                            Input {
+                                placeholder: "Host",
+                                value: new_hub.read().host.clone(),
+                                oninput: move |e: FormEvent| new_hub.with_mut(|hub| hub.host = e.value()),
+                            }
+                            Input {
+                                placeholder: "Fingerprint",
+                                value: new_hub.read().pk.clone(),
+                                oninput: move |e: FormEvent| new_hub.with_mut(|hub| hub.pk = e.value()),
+                            }
+                        }
+
+                        Button {
+                            r#type: "submit",
+                            variant: ButtonVariant::Ghost,
+                            class: "px-0 shrink-0",
+                            disabled: is_new_hub_invalid,
+                            Plus { class: "stroke-primary" }
+                        }
+                    }
+                }
+
+                div { class: "relative mt-8 mb-5 flex items-center justify-center",
+                    Separator { class: "w-full" }
+
+                    div { class: "text-muted-foreground bg-background absolute flex items-center gap-2 px-4 text-sm font-semibold",
+                        span { class: "tracking-wider", "Clipboard" }
+                        ClipboardX { class: "" }
+                    }
+                }
+
+                div { class: "flex items-center gap-2 text-sm",
+                    span { class: "grow text-muted-foreground", "Clear copied secrets" }
+
+                    Select {
+                        class: "w-44",
+                        value: "{settings().clipboard_clear_delay}",
+                        on_value_change: move |value: String| {
+                            if let Ok(delay) = value.parse() {
+                                let mut new_settings = settings();
+                                new_settings.clipboard_clear_delay = delay;
+                                handle_change(new_settings);
+                            }
+                        },
+
+                        SelectTrigger {
+                            span { class: "grow text-left",
+                                {
+                                    CLIPBOARD_CLEAR_DELAY_OPTIONS
+                                        .iter()
+                                        .find(|(delay, _)| *delay == settings().clipboard_clear_delay)
+                                        .map(|(_, name)| name.to_string())
+                                        .unwrap_or(format!("after {} seconds", settings().clipboard_clear_delay))
+                                }
+                            }
+                        }
+                        SelectContent { class: "",
+                            for (delay , name) in CLIPBOARD_CLEAR_DELAY_OPTIONS {
+                                SelectItem { value: "{delay}",
+                                    span { "{name}" }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                div { class: "relative mt-8 mb-5 flex items-center justify-center",
+                    Separator { class: "w-full" }
+
+                    div { class: "text-muted-foreground bg-background absolute flex items-center gap-2 px-4 text-sm font-semibold",
+                        span { class: "tracking-wider", "Vault" }
+                        Vault { class: "" }
+                    }
+                }
+
+                if is_vault_on() {
+                    div { class: "flex flex-col gap-4 text-sm",
+                        div { class: "flex items-center gap-2",
+                            ShieldCheck { class: "stroke-green-500 shrink-0" }
+                            span { class: "grow text-muted-foreground",
+                                "Secrets are encrypted with your passphrase, ping history and unlock times are not"
+                            }
+
+                            Button {
+                                r#type: "button",
+                                variant: ButtonVariant::Ghost,
+                                class: "shrink-0 gap-2",
+                                onclick: move |_| handle_lock_vault(),
+                                "Lock"
+                                Lock { class: "" }
+                            }
+
+                            Button {
+                                r#type: "button",
+                                variant: ButtonVariant::Ghost,
+                                class: "shrink-0 gap-2 text-destructive",
+                                onclick: move |_| handle_disable_vault(),
+                                "Disable"
+                                ShieldOff { class: "stroke-destructive" }
+                            }
+                        }
+
+                        div { class: "flex items-center gap-2",
+                            span { class: "grow text-muted-foreground", "Lock when idle" }
+
+                            Select {
+                                class: "w-44",
+                                value: "{settings().vault_auto_lock}",
+                                on_value_change: move |value: String| {
+                                    if let Ok(auto_lock) = value.parse() {
+                                        let mut new_settings = settings();
+                                        new_settings.vault_auto_lock = auto_lock;
+                                        handle_change(new_settings);
+                                    }
+                                },
+
+                                SelectTrigger {
+                                    span { class: "grow text-left",
+                                        {
+                                            VAULT_AUTO_LOCK_OPTIONS
+                                                .iter()
+                                                .find(|(auto_lock, _)| *auto_lock == settings().vault_auto_lock)
+                                                .map(|(_, name)| name.to_string())
+                                                .unwrap_or(format!("after {} seconds", settings().vault_auto_lock))
+                                        }
+                                    }
+                                }
+                                SelectContent { class: "",
+                                    for (auto_lock , name) in VAULT_AUTO_LOCK_OPTIONS {
+                                        SelectItem { value: "{auto_lock}",
+                                            span { "{name}" }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                } else {
+                    form {
+                        class: "flex flex-col gap-2 text-sm",
+                        onsubmit: move |e: FormEvent| {
+                            e.prevent_default();
+                            handle_enable_vault();
+                        },
+
+                        span { class: "text-muted-foreground",
+                            "Encrypt stored secrets with a passphrase, it can't be recovered if lost"
+                        }
+
+                        span { class: "text-xs text-muted-foreground",
+                            "Ping history and unlock times stay unencrypted, so secrets keep being pinged and checked while the vault is locked"
+                        }
+
+                        div { class: "flex items-center gap-2",
+                            div { class: "grid grow grid-cols-2 gap-2",
+                                Input {
+                                    r#type: "password",
+                                    placeholder: "Passphrase",
+                                    value: new_passphrase(),
+                                    oninput: move |e: FormEvent| new_passphrase.set(e.value()),
+                                }
+                                Input {
+                                    r#type: "password",
+                                    placeholder: "Confirm passphrase",
+                                    value: confirm_passphrase(),
+                                    oninput: move |e: FormEvent| confirm_passphrase.set(e.value()),
+                                }
+                            }
+
+                            Button {
+                                r#type: "submit",
+                                variant: ButtonVariant::Ghost,
+                                class: "shrink-0 gap-2",
+                                disabled: is_new_passphrase_invalid,
+                                "Enable"
+                                ShieldCheck { class: "stroke-primary" }
+                            }
+                        }
+                    }
+                }
+
+                div { class: "relative mt-8 mb-5 flex items-center justify-center",
+                    Separator { class: "w-full" }
+
+                    div { class: "text-muted-foreground bg-background absolute flex items-center gap-2 px-4 text-sm font-semibold",
+                        span { class: "tracking-wider", "Backup" }
+                        DatabaseBackup { class: "" }
+                    }
+                }
+
+                div { class: "flex flex-col gap-2 text-sm",
+                    span { class: "text-muted-foreground",
+                        "Save every secret and the settings to a file, optionally protected by a passphrase"
+                    }
+
+                    form {
+                        class: "flex items-center gap-2",
+                        onsubmit: move |e: FormEvent| async move {
+                            e.prevent_default();
+                            handle_export_backup().await;
+                        },
+
+                        Input {
+                            r#type: "password",
+                            placeholder: "Passphrase (optional)",
+                            value: backup_passphrase(),
+                            oninput: move |e: FormEvent| backup_passphrase.set(e.value()),
+                        }
+
+                        Button {
+                            r#type: "submit",
+                            variant: ButtonVariant::Ghost,
+                            class: "shrink-0 gap-2",
+                            "Export"
+                            Download { class: "" }
+                        }
+                    }
+
+                    div { class: "flex items-center gap-2",
+                        span { class: "grow text-muted-foreground", "Restore from a backup file" }
+
+                        label { class: "inline-flex h-9 shrink-0 cursor-pointer items-center gap-2 rounded-md px-4 font-medium hover:bg-accent hover:text-accent-foreground",
+                            "Restore"
+                            ArchiveRestore { class: "" }
+
+                            input {
+                                r#type: "file",
+                                accept: ".json,application/json",
+                                class: "hidden",
+                                onchange: handle_pick_backup,
+                            }
+                        }
+                    }
+                }
+
+                div { class: "relative mt-8 mb-5 flex items-center justify-center",
+                    Separator { class: "w-full" }
+
+                    div { class: "text-muted-foreground bg-background absolute flex items-center gap-2 px-4 text-sm font-semibold",
+                        span { class: "tracking-wider", "Appearance" }
+                        Palette { class: "" }
+                    }
+                }
+
+                div { class: "flex items-center gap-2 text-sm",
+                    span { class: "grow text-muted-foreground", "Theme" }
+
+                    Select {
+                        class: "w-28",
+                        value: "{settings().theme}",
+                        on_value_change: move |value: String| {
+                            if let Ok(theme) = value.parse::<Theme>() {
+                                let mut new_settings = settings();
+                                new_settings.theme = theme;
+                                handle_change(new_settings);
+                            }
+                        },
+
+                        SelectTrigger { SelectValue {} }
+                        SelectContent { class: "",
+                            for theme in Theme::iter() {
+                                SelectItem { value: "{theme}",
+                                    span { "{theme}" }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        RestoreBackupDialog {
+            backup_json: restoring_backup_json(),
+            on_close: move |_| restoring_backup_json.set(None),
+            on_restored: handle_restored,
+        }
+    }
+}
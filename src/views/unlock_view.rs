@@ -0,0 +1,82 @@
+use dioxus::prelude::*;
+
+use lucide_dioxus::{LockOpen, Vault};
+
+use wingedcap::get_current_unix_time;
+
+use crate::storage::unlock_vault;
+
+use crate::types::VaultSession;
+
+use crate::ui::toast::{use_toast, ToastRenderer};
+
+use crate::ui::{
+    button::Button,
+    card::{Card, CardContent, CardDescription, CardHeader, CardTitle},
+    input::Input,
+};
+
+#[component]
+pub fn UnlockView() -> Element {
+    let mut toast = use_toast();
+
+    let VaultSession {
+        mut is_locked,
+        mut last_activity,
+    } = use_context::<VaultSession>();
+
+    let mut passphrase = use_signal(String::new);
+
+    let mut handle_unlock = move || match unlock_vault(&passphrase()) {
+        Ok(_) => {
+            passphrase.set(String::new());
+            last_activity.set(get_current_unix_time());
+            is_locked.set(false);
+        }
+
+        Err(e) => {
+            tracing::error!("error unlocking vault: {:?}", e);
+            toast.error("Wrong passphrase");
+        }
+    };
+
+    rsx! {
+        Card { class: "animate-fade-in",
+            CardHeader { class: "mb-6",
+                CardTitle { class: "flex items-center gap-2",
+                    span { "Locked" }
+                    Vault { class: "" }
+                }
+                CardDescription {
+                    "Your secrets are encrypted on this device, pings resume once unlocked"
+                }
+            }
+
+            CardContent {
+                form {
+                    class: "flex items-center gap-2",
+                    onsubmit: move |e: FormEvent| {
+                        e.prevent_default();
+                        handle_unlock();
+                    },
+
+                    Input {
+                        r#type: "password",
+                        class: "grow",
+                        placeholder: "Passphrase",
+                        value: passphrase(),
+                        oninput: move |e: FormEvent| passphrase.set(e.value()),
+                    }
+
+                    Button {
+                        r#type: "submit",
+                        class: "shrink-0 gap-2",
+                        disabled: passphrase.read().is_empty(),
+                        "Unlock"
+                        LockOpen { class: "" }
+                    }
+                }
+            }
+        }
+    }
+}